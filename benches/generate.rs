@@ -0,0 +1,38 @@
+//! Measures the throughput of `Generator::next`. Run with `cargo bench`.
+//!
+//! To report a change before and after, save a baseline on the parent commit
+//! with `cargo bench --bench generate -- --save-baseline before`, then run
+//! `cargo bench --bench generate -- --baseline before` on the change, and
+//! criterion prints the difference for each case.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use names::{Casing, Generator, GeneratorBuilder, Name, NumberSeperator, SmallRng};
+use rand::SeedableRng;
+
+fn generator(casing: Casing, naming: Name) -> Generator<SmallRng> {
+    GeneratorBuilder::default()
+        .casing(casing)
+        .naming(naming)
+        .rng(SmallRng::seed_from_u64(0))
+        .build()
+        .unwrap()
+}
+
+fn next(c: &mut Criterion) {
+    let cases = [
+        ("default", Casing::default(), Name::Plain),
+        ("camel", Casing::CamelCase, Name::Plain),
+        ("default numbered", Casing::default(), Name::Numbered(4, NumberSeperator::Dash)),
+        ("title", Casing::TitleCase(NumberSeperator::Dash), Name::Plain),
+    ];
+
+    let mut group = c.benchmark_group("next");
+    for (label, casing, naming) in cases {
+        let mut generator = generator(casing, naming);
+        group.bench_function(label, |b| b.iter(|| black_box(generator.next())));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, next);
+criterion_main!(benches);
@@ -15,6 +15,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         out_dir.join("adjectives.rs"),
     )?;
     generate(src_dir.join("nouns.txt"), out_dir.join("nouns.rs"))?;
+    for locale in ["es", "de"] {
+        for list in ["adjectives", "nouns"] {
+            generate(
+                src_dir.join(format!("{}_{}.txt", list, locale)),
+                out_dir.join(format!("{}_{}.rs", list, locale)),
+            )?;
+        }
+    }
     Ok(())
 }
 
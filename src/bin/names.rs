@@ -1,20 +1,66 @@
-use names::GeneratorBuilder;
+use std::{fs::File, io::BufReader, path::Path, process};
+
+use names::{Error, GeneratorBuilder, StdRng};
+use rand::SeedableRng;
 
 fn main() {
     let args = cli::parse();
 
-    GeneratorBuilder::default()
+    let mut builder = GeneratorBuilder::default();
+    builder
         .naming(args.naming())
-        .rng(rand::thread_rng())
-        .build()
-        .unwrap()
-        .take(args.amount)
-        .for_each(|name| println!("{}", name));
+        .casing(args.casing.clone())
+        .rng(match args.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        });
+    if let Some(path) = &args.adjectives {
+        builder.adjectives_from_reader(open(path)).unwrap_or_else(|err| exit(err));
+    }
+    if let Some(path) = &args.nouns {
+        builder.nouns_from_reader(open(path)).unwrap_or_else(|err| exit(err));
+    }
+
+    let generator = builder.build().unwrap_or_else(|err| exit(err));
+    let mut names: Box<dyn Iterator<Item = String>> = if args.unique {
+        Box::new(generator.unique())
+    } else {
+        Box::new(generator)
+    };
+    for printed in 0..args.amount {
+        match names.next() {
+            Some(name) => println!("{}", name),
+            None if args.unique => {
+                eprintln!("note: only {} unique names are available", printed);
+                return;
+            }
+            None => exit(Error::EmptyIterator),
+        }
+    }
+}
+
+/// Prints the error to stderr and exits with a non-zero code
+fn exit(err: Error) -> ! {
+    eprintln!("error: {}", err);
+    process::exit(1);
+}
+
+/// Opens a newline-delimited word list for reading
+fn open(path: &Path) -> BufReader<File> {
+    match File::open(path) {
+        Ok(file) => BufReader::new(file),
+        Err(err) => {
+            eprintln!("error: failed to read {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    }
 }
 
 mod cli {
+    use std::path::PathBuf;
+
     use clap::Parser;
-    use names::{Name, NumberSeperator};
+    use names::{Casing, Name, NumberSeperator};
 
     const AUTHOR: &str = concat!(env!("CARGO_PKG_AUTHORS"), "\n\n");
     const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -31,6 +77,32 @@ mod cli {
         #[clap(short, long)]
         pub(crate) number: Option<usize>,
 
+        /// Separator placed before the number, such as "_", or "" for none
+        #[clap(long, default_value = "-")]
+        pub(crate) separator: NumberSeperator,
+
+        /// Casing style of the name(s), such as "camel", "pascal" or "snake"
+        #[clap(short, long, default_value = "lower")]
+        pub(crate) casing: Casing,
+
+        /// File of newline-delimited adjectives to use instead of the built-in
+        /// list, skipping blank lines and lines starting with "#"
+        #[clap(long, value_name = "PATH")]
+        pub(crate) adjectives: Option<PathBuf>,
+
+        /// File of newline-delimited nouns to use instead of the built-in list,
+        /// skipping blank lines and lines starting with "#"
+        #[clap(long, value_name = "PATH")]
+        pub(crate) nouns: Option<PathBuf>,
+
+        /// Prints distinct names, stopping early if they run out
+        #[clap(short, long)]
+        pub(crate) unique: bool,
+
+        /// Seed for the random number generator, for reproducible output
+        #[clap(short, long)]
+        pub(crate) seed: Option<u64>,
+
         /// Number of names to generate
         #[clap(default_value = "1", rename_all = "screaming_snake_case")]
         pub(crate) amount: usize,
@@ -39,7 +111,7 @@ mod cli {
     impl Args {
         pub(crate) fn naming(&self) -> Name {
             if let Some(number) = self.number {
-                Name::ZeroPaddedNumbered(number, NumberSeperator::Dash)
+                Name::ZeroPaddedNumbered(number, self.separator.clone())
             } else {
                 Name::default()
             }
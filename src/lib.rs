@@ -12,8 +12,28 @@
 //!
 //! ```toml
 //! [dependencies]
-//! names = { version = "0.14", default-features = false }
+//! names = { version = "0.16", default-features = false, features = ["std"] }
 //! ```
+//!
+//! ## Features
+//!
+//! - `std` (enabled by default): uses the standard library. Without it the
+//!   crate is `#![no_std]` and only requires `alloc`, but the entropy-seeded
+//!   RNGs (such as `ThreadRng`), the `Default` and `Deserialize` implementations
+//!   of `Generator`, `UniqueGenerator` and `BoxedGenerator` are unavailable.
+//!   Seed a generator with `GeneratorBuilder::seed` or `Generator::from_seed`,
+//!   or supply your own RNG instead. This is also the way to use the crate on
+//!   `wasm32-unknown-unknown` without `getrandom`'s `js` feature.
+//! - `application` (enabled by default): builds the `names` CLI.
+//! - `stream`: provides `Generator::into_stream`, which yields names
+//!   asynchronously through a `NameStream` implementing `futures::Stream`.
+//! - `words-file`: provides `GeneratorBuilder::from_words_file`, which reads
+//!   the adjectives and nouns from a single JSON or TOML file.
+//! - `length-unicode`: makes `Length` count grapheme clusters rather than
+//!   `char`s, so that a letter with a combining accent or an emoji made of
+//!   several code points counts once. It depends on the `unicode-segmentation`
+//!   crate.
+//!
 //! ## Examples
 //!
 //! ### Example: painless defaults
@@ -22,9 +42,9 @@
 //! a name:
 //!
 //! ```
-//! use names::Generator;
+//! use names::{Generator, ThreadRng};
 //!
-//! let mut generator = Generator::default();
+//! let mut generator = Generator::<ThreadRng>::default();
 //! println!("Your project is: {}", generator.next().unwrap());
 //! // #=> "Your project is: rusty-nail"
 //! ```
@@ -54,8 +74,8 @@
 //! ```
 //! use names::{GeneratorBuilder, Name, ThreadRng};
 //!
-//! let adjectives = &["imaginary"];
-//! let nouns = &["roll"];
+//! let adjectives = vec!["imaginary".to_string()];
+//! let nouns = vec!["roll".to_string()];
 //! let mut generator = GeneratorBuilder::default()
 //!     .adjectives(adjectives)
 //!     .nouns(nouns)
@@ -66,15 +86,33 @@
 //! assert_eq!("imaginary-roll", generator.next().unwrap());
 //! ```
 
-#![doc(html_root_url = "https://docs.rs/names/0.14.1-dev")]
+#![doc(html_root_url = "https://docs.rs/names/0.16.0")]
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
-use core::{fmt, str::FromStr, convert::{Infallible, TryFrom}};
+use alloc::{borrow::Cow, collections::BTreeSet, format, string::{String, ToString}, sync::Arc, vec, vec::Vec};
+use core::{fmt, ops::RangeInclusive, str::FromStr, convert::{Infallible, TryFrom}};
+#[cfg(feature = "stream")]
+use core::{pin::Pin, task::{Context, Poll}};
+#[cfg(feature = "stream")]
+use futures_core::Stream;
+#[cfg(feature = "std")]
+use std::{collections::HashSet, io::BufRead, sync::{Mutex, MutexGuard, PoisonError}};
+#[cfg(feature = "words-file")]
+use std::{fs, path::Path};
+#[cfg(feature = "length-unicode")]
+use unicode_segmentation::UnicodeSegmentation;
 
 use derive_builder::{Builder, UninitializedFieldError};
-use rand::{seq::SliceRandom, Rng};
+use rand::{distributions::{Distribution, WeightedIndex}, seq::index, Rng, SeedableRng};
+#[cfg(feature = "std")]
+use rand::RngCore;
 pub use rand::rngs::*;
 use serde::{Serialize, Deserialize, Deserializer};
+#[cfg(feature = "std")]
+use serde::de::{value::{MapAccessDeserializer, StringDeserializer}, DeserializeSeed, MapAccess, Visitor};
 
 /// List of English adjective words
 pub const ADJECTIVES: &[&str] = &include!(concat!(env!("OUT_DIR"), "/adjectives.rs"));
@@ -82,10 +120,81 @@ pub const ADJECTIVES: &[&str] = &include!(concat!(env!("OUT_DIR"), "/adjectives.
 /// List of English noun words
 pub const NOUNS: &[&str] = &include!(concat!(env!("OUT_DIR"), "/nouns.rs"));
 
+/// The number of words in [`ADJECTIVES`]
+pub const ADJECTIVE_COUNT: usize = ADJECTIVES.len();
+
+/// The number of words in [`NOUNS`]
+pub const NOUN_COUNT: usize = NOUNS.len();
+
+/// The number of plain names the built-in [`ADJECTIVES`] and [`NOUNS`] make
+/// with a single adjective
+pub const BUILTIN_COMBINATIONS: u128 = ADJECTIVE_COUNT as u128 * NOUN_COUNT as u128;
+
+/// List of Spanish adjective words, transliterated to ASCII
+pub const ADJECTIVES_ES: &[&str] = &include!(concat!(env!("OUT_DIR"), "/adjectives_es.rs"));
+
+/// List of Spanish noun words, transliterated to ASCII
+pub const NOUNS_ES: &[&str] = &include!(concat!(env!("OUT_DIR"), "/nouns_es.rs"));
+
+/// List of German adjective words, transliterated to ASCII
+pub const ADJECTIVES_DE: &[&str] = &include!(concat!(env!("OUT_DIR"), "/adjectives_de.rs"));
+
+/// List of German noun words, transliterated to ASCII
+pub const NOUNS_DE: &[&str] = &include!(concat!(env!("OUT_DIR"), "/nouns_de.rs"));
+
+/// The largest digit count of a [`Name::Numbered`] or
+/// [`Name::ZeroPaddedNumbered`], the most that fit in a `u64`
+pub const MAX_DIGITS: usize = 19;
+
+/// The language of the built-in word lists
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Locale {
+    /// English, using [`ADJECTIVES`] and [`NOUNS`]
+    #[default]
+    En,
+    /// Spanish, using [`ADJECTIVES_ES`] and [`NOUNS_ES`]
+    Es,
+    /// German, using [`ADJECTIVES_DE`] and [`NOUNS_DE`]
+    De,
+}
+
+impl Locale {
+    /// Returns the built-in adjectives of the locale
+    pub fn adjectives(self) -> &'static [&'static str] {
+        match self {
+            Locale::En => ADJECTIVES,
+            Locale::Es => ADJECTIVES_ES,
+            Locale::De => ADJECTIVES_DE,
+        }
+    }
+
+    /// Returns the built-in nouns of the locale
+    pub fn nouns(self) -> &'static [&'static str] {
+        match self {
+            Locale::En => NOUNS,
+            Locale::Es => NOUNS_ES,
+            Locale::De => NOUNS_DE,
+        }
+    }
+}
+
 /// A naming strategy for the [`Generator`]
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+///
+/// A name is composed in order: the [`Casing`] joins the adjectives and noun
+/// with its own seperator, then the number or affix is attached with the
+/// strategy's [`NumberSeperator`], which the casing never alters. Only
+/// [`Casing::CamelCase`], [`Casing::PascalCase`] and [`Casing::FlatCase`],
+/// which join the words without a seperator, drop the seperator of a decimal
+/// number too. An empty seperator given explicitly, such as
+/// `Casing::Lowercase(NumberSeperator::None)`, keeps it, and the seperator of
+/// an affix, a hexadecimal token or an encoded suffix is always kept. An affix
+/// is cased as a word on its own, and a hexadecimal token is always lowercase. For example, [`Casing::CamelCase`] with
+/// `Name::Numbered(2, NumberSeperator::Dash)` yields `"rustyNail42"`, and
+/// [`Casing::KebabCase`] `"rusty-nail-42"`.
+#[derive(Serialize, Deserialize, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Name {
     /// This represents a plain naming strategy of the form `"ADJECTIVE-NOUN"`
+    #[default]
     Plain,
     /// This represents a naming strategy with a random number appended to the
     /// end, of the form `"ADJECTIVE-NOUN{seperator}NUMBER"`
@@ -93,32 +202,273 @@ pub enum Name {
     /// This represents a naming strategy with a zero-padded number appended to
     /// the end, of the form `"ADJECTIVE-NOUN{seperator}NUMBER"`
     ZeroPaddedNumbered(usize, NumberSeperator),
+    /// This represents a naming strategy with a fixed token prepended to the
+    /// start, of the form `"PREFIX{seperator}ADJECTIVE-NOUN"`. The prefix has
+    /// the [`Casing`] applied to it as a single word.
+    Prefixed(String, NumberSeperator),
+    /// This represents a naming strategy with a fixed token appended to the
+    /// end, of the form `"ADJECTIVE-NOUN{seperator}SUFFIX"`. The suffix has
+    /// the [`Casing`] applied to it as a single word.
+    Suffixed(String, NumberSeperator),
+    /// This represents a naming strategy with a random lowercase hexadecimal
+    /// token appended to the end, of the form `"ADJECTIVE-NOUN{seperator}HEX"`
+    HexSuffixed(usize, NumberSeperator),
+    /// This represents a naming strategy with a random number between a
+    /// minimum and maximum, inclusive, appended to the end, of the form
+    /// `"ADJECTIVE-NOUN{seperator}NUMBER"`. See [`Name::numbered_range`].
+    NumberedRange(u64, u64, NumberSeperator),
+    /// This represents a naming strategy with the current Unix time in seconds
+    /// appended to the end, of the form `"ADJECTIVE-NOUN{seperator}SECONDS"`,
+    /// so that names sort by creation time. Names generated within the same
+    /// second share a timestamp, so they are not unique. Reading the clock
+    /// needs the `std` feature, without which building fails with
+    /// [`Error::RequiresStd`].
+    Timestamped(NumberSeperator),
+    /// This represents a naming strategy with the position of the name in the
+    /// generator's sequence, starting at 1, appended to the end, of the form
+    /// `"ADJECTIVE-NOUN{seperator}NUMBER"`. The numbers depend on the order in
+    /// which names are generated, and repeat across generators.
+    Sequential(NumberSeperator),
+    /// This represents a naming strategy with `chars` symbols drawn at random
+    /// from `alphabet` appended to the end, of the form
+    /// `"ADJECTIVE-NOUN{sep}SYMBOLS"`, such as base-36 with
+    /// `"0123456789abcdefghijklmnopqrstuvwxyz"`. The symbols are not cased,
+    /// and building fails with [`Error::DuplicateSymbol`] if one repeats.
+    EncodedSuffix {
+        /// The number of symbols
+        chars: usize,
+        /// The symbols to draw from
+        alphabet: String,
+        /// The seperator before the symbols
+        sep: NumberSeperator,
+    },
+    /// This represents a naming strategy with a random number of between
+    /// `min_digits` and `max_digits` digits, inclusive, appended to the end,
+    /// of the form `"ADJECTIVE-NOUN{sep}NUMBER"`. The digit count is drawn
+    /// first, then a number with that many digits.
+    VariableNumbered {
+        /// The fewest digits
+        min_digits: usize,
+        /// The most digits
+        max_digits: usize,
+        /// The seperator before the number
+        sep: NumberSeperator,
+    },
 }
 
-impl Default for Name {
-    fn default() -> Self {
-        Name::Plain
+impl Name {
+    /// Returns a [`Name::NumberedRange`] drawing numbers from the given range
+    pub fn numbered_range(range: RangeInclusive<u64>, seperator: NumberSeperator) -> Name {
+        let (start, end) = range.into_inner();
+        Name::NumberedRange(start, end, seperator)
+    }
+
+    /// Returns the seperator between the name and the token this strategy
+    /// attaches, or `None` for [`Name::Plain`]
+    fn seperator(&self) -> Option<&NumberSeperator> {
+        match self {
+            Name::Plain => None,
+            Name::Numbered(_, sep)
+            | Name::ZeroPaddedNumbered(_, sep)
+            | Name::Prefixed(_, sep)
+            | Name::Suffixed(_, sep)
+            | Name::HexSuffixed(_, sep)
+            | Name::NumberedRange(_, _, sep)
+            | Name::Sequential(sep)
+            | Name::EncodedSuffix { sep, .. }
+            | Name::VariableNumbered { sep, .. }
+            | Name::Timestamped(sep) => Some(sep),
+        }
+    }
+
+    /// Returns whether the token this strategy attaches is a decimal number
+    fn is_numeric(&self) -> bool {
+        match self {
+            Name::Numbered(..)
+            | Name::ZeroPaddedNumbered(..)
+            | Name::NumberedRange(..)
+            | Name::Sequential(_)
+            | Name::VariableNumbered { .. }
+            | Name::Timestamped(_) => true,
+            Name::Plain | Name::Prefixed(..) | Name::Suffixed(..) | Name::HexSuffixed(..) | Name::EncodedSuffix { .. } => false,
+        }
+    }
+
+    /// Returns the number of distinct tokens this strategy can attach to a
+    /// name, saturating at `u128::MAX`
+    fn combinations(&self) -> u128 {
+        match self {
+            Name::Plain | Name::Prefixed(..) | Name::Suffixed(..) => 1,
+            Name::Numbered(x, _) => pow_saturating(10, x.saturating_sub(1)).saturating_mul(9),
+            Name::ZeroPaddedNumbered(x, _) => pow_saturating(10, *x),
+            Name::HexSuffixed(x, _) => pow_saturating(16, *x),
+            Name::NumberedRange(start, end, _) => u128::from(end.saturating_sub(*start)) + 1,
+            Name::Timestamped(_) => 1,
+            Name::Sequential(_) => u128::from(u64::MAX),
+            Name::EncodedSuffix { chars, alphabet, .. } => pow_saturating(alphabet.chars().count() as u128, *chars),
+            Name::VariableNumbered { min_digits, max_digits, .. } => {
+                pow_saturating(10, *max_digits).saturating_sub(pow_saturating(10, min_digits.saturating_sub(1)))
+            },
+        }
+    }
+
+    /// Checks the arguments of the naming strategy, as done when building
+    fn validate(&self) -> Result<(), Error> {
+        match *self {
+            Name::Numbered(0, _)
+            | Name::ZeroPaddedNumbered(0, _)
+            | Name::HexSuffixed(0, _)
+            | Name::EncodedSuffix { chars: 0, .. }
+            | Name::VariableNumbered { min_digits: 0, .. } => Err(Error::ZeroDigits),
+            Name::VariableNumbered { min_digits, max_digits, .. } if min_digits > max_digits => {
+                Err(Error::InvalidDigitRange(min_digits, max_digits))
+            },
+            Name::VariableNumbered { max_digits: x, .. } | Name::Numbered(x, _) | Name::ZeroPaddedNumbered(x, _) if x > MAX_DIGITS => {
+                Err(Error::TooManyDigits(x))
+            },
+            Name::EncodedSuffix { ref alphabet, .. } if alphabet.is_empty() => Err(Error::EmptyAlphabet),
+            Name::EncodedSuffix { ref alphabet, .. } => {
+                let mut seen = BTreeSet::new();
+                match alphabet.chars().find(|&symbol| !seen.insert(symbol)) {
+                    Some(symbol) => Err(Error::DuplicateSymbol(symbol)),
+                    None => Ok(()),
+                }
+            },
+            Name::NumberedRange(start, end, _) if start > end => Err(Error::InvalidNumberRange(start, end)),
+            #[cfg(not(feature = "std"))]
+            Name::Timestamped(_) => Err(Error::RequiresStd),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the shortest and longest character lengths of the number or
+    /// affix this strategy adds to a name, excluding its seperator
+    fn width(&self) -> (usize, usize) {
+        let digits = |n: u64| n.to_string().len();
+        match self {
+            Name::Plain => (0, 0),
+            Name::Numbered(x, _) | Name::ZeroPaddedNumbered(x, _) | Name::HexSuffixed(x, _) => (*x, *x),
+            Name::Prefixed(affix, _) | Name::Suffixed(affix, _) => {
+                let len = text_len(affix);
+                (len, len)
+            },
+            Name::NumberedRange(start, end, _) => (digits(*start), digits(*end)),
+            Name::Timestamped(_) => {
+                let len = digits(unix_timestamp());
+                (len, len)
+            },
+            Name::Sequential(_) => (1, digits(u64::MAX)),
+            Name::EncodedSuffix { chars, .. } => (*chars, *chars),
+            Name::VariableNumbered { min_digits, max_digits, .. } => (*min_digits, *max_digits),
+        }
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Name::Plain => f.write_str("plain"),
+            Name::Numbered(x, seperator) => write!(f, "numbered({},{})", x, seperator),
+            Name::ZeroPaddedNumbered(x, seperator) => write!(f, "zero-padded({},{})", x, seperator),
+            Name::Prefixed(prefix, seperator) => write!(f, "prefixed({},{})", prefix, seperator),
+            Name::Suffixed(suffix, seperator) => write!(f, "suffixed({},{})", suffix, seperator),
+            Name::HexSuffixed(x, seperator) => write!(f, "hex({},{})", x, seperator),
+            Name::NumberedRange(start, end, seperator) => write!(f, "numbered-range({},{},{})", start, end, seperator),
+            Name::Timestamped(seperator) => write!(f, "timestamped({})", seperator),
+            Name::Sequential(seperator) => write!(f, "sequential({})", seperator),
+            Name::EncodedSuffix { chars, alphabet, sep } => write!(f, "encoded({},{},{})", chars, alphabet, sep),
+            Name::VariableNumbered { min_digits, max_digits, sep } => write!(f, "variable-numbered({},{},{})", min_digits, max_digits, sep),
+        }
+    }
+}
+/// Parses the form written by [`Display`](fmt::Display), such as `"plain"` or
+/// `"numbered(4,-)"`, or the same arguments separated by colons, such as
+/// `"numbered:4:-"` or `"zero-padded:3:_"`. The seperator is always the last
+/// argument, and may contain the delimiter, but a prefix, suffix or alphabet may
+/// not.
+impl FromStr for Name {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Name, <Self as FromStr>::Err> {
+        let unknown = || Error::UnknownNaming(s.into());
+        if s == "plain" {
+            return Result::Ok(Name::Plain);
+        }
+
+        let (kind, args, delimiter) = match s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            Some((kind, args)) => (kind, args, ','),
+            None => s.split_once(':').map(|(kind, args)| (kind, args, ':')).ok_or_else(unknown)?,
+        };
+        let digits = |args: &str| -> Result<(usize, NumberSeperator), Error> {
+            let (x, seperator) = args.split_once(delimiter).ok_or_else(unknown)?;
+            Result::Ok((x.parse().map_err(|_| unknown())?, seperator.into()))
+        };
+        Result::Ok(match kind {
+            "numbered" => digits(args).map(|(x, seperator)| Name::Numbered(x, seperator))?,
+            "zero-padded" => digits(args).map(|(x, seperator)| Name::ZeroPaddedNumbered(x, seperator))?,
+            "hex" => digits(args).map(|(x, seperator)| Name::HexSuffixed(x, seperator))?,
+            "prefixed" | "suffixed" => {
+                let (affix, seperator) = args.split_once(delimiter).ok_or_else(unknown)?;
+                match kind {
+                    "prefixed" => Name::Prefixed(affix.into(), seperator.into()),
+                    _ => Name::Suffixed(affix.into(), seperator.into()),
+                }
+            },
+            "numbered-range" => {
+                let (start, rest) = args.split_once(delimiter).ok_or_else(unknown)?;
+                let (end, seperator) = rest.split_once(delimiter).ok_or_else(unknown)?;
+                Name::NumberedRange(start.parse().map_err(|_| unknown())?, end.parse().map_err(|_| unknown())?, seperator.into())
+            },
+            "timestamped" => Name::Timestamped(args.into()),
+            "sequential" => Name::Sequential(args.into()),
+            "encoded" => {
+                let (chars, rest) = args.split_once(delimiter).ok_or_else(unknown)?;
+                let (alphabet, seperator) = rest.split_once(delimiter).ok_or_else(unknown)?;
+                Name::EncodedSuffix { chars: chars.parse().map_err(|_| unknown())?, alphabet: alphabet.into(), sep: seperator.into() }
+            },
+            "variable-numbered" => {
+                let (min, rest) = args.split_once(delimiter).ok_or_else(unknown)?;
+                let (max, seperator) = rest.split_once(delimiter).ok_or_else(unknown)?;
+                Name::VariableNumbered {
+                    min_digits: min.parse().map_err(|_| unknown())?,
+                    max_digits: max.parse().map_err(|_| unknown())?,
+                    sep: seperator.into(),
+                }
+            },
+            _ => return Result::Err(unknown()),
+        })
     }
 }
 
+/// Where the number of a [`Name::Numbered`], [`Name::ZeroPaddedNumbered`],
+/// [`Name::NumberedRange`], [`Name::Timestamped`], [`Name::Sequential`] or
+/// [`Name::VariableNumbered`] naming strategy is placed
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum NumberPosition {
+    /// This places the number before the name, of the form
+    /// `"NUMBER{seperator}ADJECTIVE-NOUN"`
+    Prefix,
+    /// This places the number after the name, of the form
+    /// `"ADJECTIVE-NOUN{seperator}NUMBER"`
+    #[default]
+    Suffix,
+}
+
 /// A seperator for the [`Generator`]. This is only applied if there are any digits on the end or within certain [`Casing`]s.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum NumberSeperator {
     /// This represents a seperator of the form `"ADJECTIVE-NOUN"`
+    #[default]
     Dash,
     /// This represents a seperator of the form `"ADJECTIVE_NOUN"`
     Underscore,
+    /// This represents a seperator of the form `"ADJECTIVE NOUN"`
+    Space,
     /// A custom seperator
     Custom(String),
     /// This represents no seperator of the form `"ADJECTIVENOUN"`
     None,
 }
 
-impl Default for NumberSeperator {
-    fn default() -> Self {
-        NumberSeperator::Dash
-    }
-}
 impl FromStr for NumberSeperator {
     type Err = Infallible;
     fn from_str(
@@ -127,19 +477,30 @@ impl FromStr for NumberSeperator {
         Result::Ok(match s {
             "-" => NumberSeperator::Dash,
             "_" => NumberSeperator::Underscore,
+            " " => NumberSeperator::Space,
             "" => NumberSeperator::None,
             _ => return Result::Ok(NumberSeperator::Custom(s.into())),
         })
     }
 }
-#[allow(clippy::use_self)]
-impl TryFrom<&str> for NumberSeperator {
-    type Error = Infallible;
-    fn try_from(
-        s: &str,
-    ) -> Result<NumberSeperator, <Self as TryFrom<&str>>::Error>
-    {
-        FromStr::from_str(s)
+impl NumberSeperator {
+    /// Returns the seperator as a string slice
+    fn as_str(&self) -> &str {
+        match self {
+            NumberSeperator::Dash => "-",
+            NumberSeperator::Underscore => "_",
+            NumberSeperator::Space => " ",
+            NumberSeperator::Custom(s) => s,
+            NumberSeperator::None => "",
+        }
+    }
+}
+impl From<&str> for NumberSeperator {
+    fn from(s: &str) -> NumberSeperator {
+        match FromStr::from_str(s) {
+            Ok(seperator) => seperator,
+            Err(never) => match never {},
+        }
     }
 }
 impl fmt::Display for NumberSeperator {
@@ -150,6 +511,7 @@ impl fmt::Display for NumberSeperator {
         match *self {
             NumberSeperator::Dash => f.pad("-"),
             NumberSeperator::Underscore => f.pad("_"),
+            NumberSeperator::Space => f.pad(" "),
             NumberSeperator::Custom(ref s) => f.pad(s),
             NumberSeperator::None => f.pad(""),
         }
@@ -175,21 +537,83 @@ impl<'de> Deserialize<'de> for NumberSeperator {
 }
 
 /// A length for the [`Generator`]
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+///
+/// Characters are counted as `char`s, or as grapheme clusters with the
+/// `length-unicode` feature, so that a truncated name never splits a letter
+/// from its combining accents.
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Length {
-    /// This forces the generator to truncate the generated name to the given length.
+    /// This forces the generator to truncate the generated name to the given
+    /// number of characters (not bytes), so multi-byte words are never split.
     Truncate(usize),
-    /// This forces the generator to reroll the generated name until it is the given length.
+    /// This forces the generator to truncate the generated name to at most the
+    /// given number of bytes, for limits such as DNS which count bytes. The
+    /// name is cut at the last character boundary within the limit, so
+    /// multi-byte characters are never split.
+    TruncateBytes(usize),
+    /// This forces the generator to reroll the generated name until it is the
+    /// given number of characters (not bytes) long. Each attempt regenerates
+    /// the whole name, including any number, and the generator gives up after
+    /// `max_reroll_attempts` attempts.
+    ///
+    /// Building fails with [`Error::UnreachableLength`] when the length is
+    /// outside of [`Generator::length_bounds`]. Only the lengths of the words and
+    /// number are considered, so a length within the bounds may still be
+    /// unreachable, for example because of `distinct_words` or the blocklist.
     Reroll(usize),
+    /// This forces the generator to reroll the generated name until its number
+    /// of characters is within the given inclusive range. The generator gives
+    /// up after `max_reroll_attempts` attempts.
+    Range(usize, usize),
     /// No length limit
+    #[default]
     None,
 }
-impl Default for Length {
-    fn default() -> Self {
-        Length::None
-    }
+
+/// Which words make up the names of the [`Generator`], before the number or
+/// affix of the [`Name`] is attached
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Structure {
+    /// The adjectives followed by a noun, of the form `"ADJECTIVE-NOUN"`
+    #[default]
+    AdjectiveNoun,
+    /// A noun alone, of the form `"NOUN"`, ignoring `adjective_count`
+    NounOnly,
+    /// The adjectives alone, of the form `"ADJECTIVE"`
+    AdjectiveOnly,
+    /// Two different nouns, of the form `"NOUN-NOUN"`, ignoring
+    /// `adjective_count`. Repeated nouns are removed when building, keeping
+    /// the first and its weight, which fails with [`Error::TooFewNouns`]
+    /// unless at least two remain.
+    DoubleNoun,
 }
 
+/// The words and number making up a name, see [`Generator::next_parts`]
+///
+/// The words are as they appear in the dictionaries, before any transform or
+/// [`Casing`], while `formatted` is the name as returned by the generator.
+#[derive(Serialize, Deserialize, Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct NameParts {
+    /// The adjectives, joined with a space when there are several, or empty
+    /// for [`Structure::NounOnly`]. This is the leading noun of a
+    /// [`Structure::DoubleNoun`].
+    pub adjective: String,
+    /// The noun, or empty for [`Structure::AdjectiveOnly`]
+    pub noun: String,
+    /// The decimal number of the [`Name`], or `None` for the naming strategies
+    /// without one, such as [`Name::Plain`], [`Name::HexSuffixed`] and
+    /// [`Name::EncodedSuffix`]
+    pub number: Option<u64>,
+    /// The name, after the [`Casing`] and [`Length`] are applied
+    pub formatted: String,
+}
+
+/// Words which [`Casing::TitleCase`] leaves lowercase, unless they are the
+/// first or last word of the name
+pub const TITLE_CASE_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "the", "to",
+];
+
 /// A casing style for the [`Generator`]
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Casing {
@@ -207,6 +631,8 @@ pub enum Casing {
     SnakeCase,
     /// This represents a casing style of the form `"ADJECTIVE_NOUN"`
     ScreamingSnakeCase,
+    /// This represents a casing style of the form `"Adjective_Noun"`
+    CapitalizedSnakeCase,
     /// This represents a casing style of the form `"adjectiveNoun"`
     CamelCase,
     /// This represents a casing style of the form `"AdjectiveNoun"`
@@ -215,6 +641,84 @@ pub enum Casing {
     KebabCase,
     /// This represents a casing style of the form `"ADJECTIVE-NOUN"`
     ScreamingKebabCase,
+    /// This represents a casing style of the form `"Adjective-Noun"`, the
+    /// same as [`Casing::TrainCase`]
+    CapitalizedKebabCase,
+    /// This represents a casing style of the form `"Adjective of the-Noun"`,
+    /// where every whitespace-separated word is capitalized except for stop
+    /// words (such as `"of"` and `"the"`) which are not the first or last word
+    TitleCase(NumberSeperator),
+    /// This represents a casing style of the form `"adjective.noun"`
+    DotCase,
+    /// This represents a casing style of the form `"Adjective-Noun"`
+    TrainCase,
+    /// This represents a casing style of the form `"adjectivenoun"`
+    FlatCase,
+    /// This represents a casing style of the form `"aDjEcTiVe-NoUn"`, where
+    /// letters alternate between lowercase and uppercase. The alternation runs
+    /// continuously across the words, and the seperator and other non-letters
+    /// do not advance it.
+    AlternatingCase(NumberSeperator),
+    /// This represents a casing style applying `per_word` to each word and its
+    /// index, joined by `separator`. It cannot be serialized or parsed, and
+    /// ignores the title case stop words and `preserve_all_caps_words`. See
+    /// [`Casing::custom`].
+    #[serde(skip)]
+    Custom {
+        /// The callback casing each word, given the word and its index
+        per_word: CustomCasing,
+        /// The seperator joining the words
+        separator: String,
+    },
+}
+
+/// A callback which cases each word of a [`Casing::Custom`], given the word
+/// and its index
+pub type WordCasing = Arc<dyn Fn(&str, usize) -> String + Send + Sync>;
+
+/// The callback of a [`Casing::Custom`]
+///
+/// Two callbacks are equal only if they are the same [`Arc`], and are ordered
+/// and hashed by its address.
+#[derive(Clone)]
+pub struct CustomCasing(pub WordCasing);
+
+impl CustomCasing {
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl fmt::Debug for CustomCasing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CustomCasing(..)")
+    }
+}
+
+impl PartialEq for CustomCasing {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl Eq for CustomCasing {}
+
+impl PartialOrd for CustomCasing {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CustomCasing {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.address().cmp(&other.address())
+    }
+}
+
+impl core::hash::Hash for CustomCasing {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
 }
 
 impl Default for Casing {
@@ -222,123 +726,472 @@ impl Default for Casing {
         Casing::Lowercase(NumberSeperator::Dash)
     }
 }
+/// Parses names such as `"camel"` or `"screaming-snake"`. The styles taking a
+/// [`NumberSeperator`] default to [`NumberSeperator::Dash`], or accept one in
+/// parentheses, such as `"lower(_)"`.
+impl FromStr for Casing {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Casing, <Self as FromStr>::Err> {
+        let (name, seperator) = match s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            Some((name, seperator)) => (name, Some(NumberSeperator::from(seperator))),
+            None => (s, None),
+        };
+        let dash = seperator.clone().unwrap_or_default();
+        Result::Ok(match (name, seperator) {
+            ("lower", _) => Casing::Lowercase(dash),
+            ("upper", _) => Casing::Uppercase(dash),
+            ("capitalize", _) => Casing::Capitalize(dash),
+            ("capitalize-first", _) => Casing::CapitalizeFirst(dash),
+            ("capitalize-last", _) => Casing::CapitalizeLast(dash),
+            ("snake", None) => Casing::SnakeCase,
+            ("screaming-snake", None) => Casing::ScreamingSnakeCase,
+            ("capitalized-snake", None) => Casing::CapitalizedSnakeCase,
+            ("camel", None) => Casing::CamelCase,
+            ("pascal", None) => Casing::PascalCase,
+            ("kebab", None) => Casing::KebabCase,
+            ("screaming-kebab", None) => Casing::ScreamingKebabCase,
+            ("capitalized-kebab", None) => Casing::CapitalizedKebabCase,
+            ("title", _) => Casing::TitleCase(dash),
+            ("dot", None) => Casing::DotCase,
+            ("train", None) => Casing::TrainCase,
+            ("flat", None) => Casing::FlatCase,
+            ("alternating", _) => Casing::AlternatingCase(dash),
+            _ => return Result::Err(Error::UnknownCasing(s.into())),
+        })
+    }
+}
+/// Writes the form parsed by [`FromStr`], omitting a
+/// [`NumberSeperator::Dash`]. [`Casing::Custom`] is written as `"custom"`,
+/// which cannot be parsed.
+impl fmt::Display for Casing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, seperator) = match self {
+            Casing::Lowercase(seperator) => ("lower", Some(seperator)),
+            Casing::Uppercase(seperator) => ("upper", Some(seperator)),
+            Casing::Capitalize(seperator) => ("capitalize", Some(seperator)),
+            Casing::CapitalizeFirst(seperator) => ("capitalize-first", Some(seperator)),
+            Casing::CapitalizeLast(seperator) => ("capitalize-last", Some(seperator)),
+            Casing::SnakeCase => ("snake", None),
+            Casing::ScreamingSnakeCase => ("screaming-snake", None),
+            Casing::CapitalizedSnakeCase => ("capitalized-snake", None),
+            Casing::CamelCase => ("camel", None),
+            Casing::PascalCase => ("pascal", None),
+            Casing::KebabCase => ("kebab", None),
+            Casing::ScreamingKebabCase => ("screaming-kebab", None),
+            Casing::CapitalizedKebabCase => ("capitalized-kebab", None),
+            Casing::TitleCase(seperator) => ("title", Some(seperator)),
+            Casing::DotCase => ("dot", None),
+            Casing::TrainCase => ("train", None),
+            Casing::FlatCase => ("flat", None),
+            Casing::AlternatingCase(seperator) => ("alternating", Some(seperator)),
+            Casing::Custom { .. } => ("custom", None),
+        };
+        match seperator {
+            None | Some(NumberSeperator::Dash) => f.write_str(name),
+            Some(seperator) => write!(f, "{}({})", name, seperator),
+        }
+    }
+}
 impl Casing {
+    /// Returns a [`Casing::Custom`] applying `per_word` to each word and its
+    /// index, joined by `separator`
+    pub fn custom(per_word: impl Fn(&str, usize) -> String + Send + Sync + 'static, separator: impl Into<String>) -> Casing {
+        Casing::Custom { per_word: CustomCasing(Arc::new(per_word)), separator: separator.into() }
+    }
+
     /// Returns the seperator for the casing style
     pub fn seperator(&self) -> String {
+        self.seperator_str().to_string()
+    }
+
+    /// Returns the casing style with its seperator replaced, if it has one
+    fn with_seperator(&self, seperator: &NumberSeperator) -> Casing {
+        let seperator = seperator.clone();
         match self {
-            Casing::Lowercase(seperator) => seperator.to_string(),
-            Casing::Uppercase(seperator) => seperator.to_string(),
-            Casing::Capitalize(seperator) => seperator.to_string(),
-            Casing::CapitalizeFirst(seperator) => seperator.to_string(),
-            Casing::CapitalizeLast(seperator) => seperator.to_string(),
-            Casing::SnakeCase => "_".to_string(),
-            Casing::ScreamingSnakeCase => "_".to_string(),
-            Casing::CamelCase => "".to_string(),
-            Casing::PascalCase => "".to_string(),
-            Casing::KebabCase => "-".to_string(),
-            Casing::ScreamingKebabCase => "-".to_string(),
+            Casing::Lowercase(_) => Casing::Lowercase(seperator),
+            Casing::Uppercase(_) => Casing::Uppercase(seperator),
+            Casing::Capitalize(_) => Casing::Capitalize(seperator),
+            Casing::CapitalizeFirst(_) => Casing::CapitalizeFirst(seperator),
+            Casing::CapitalizeLast(_) => Casing::CapitalizeLast(seperator),
+            Casing::TitleCase(_) => Casing::TitleCase(seperator),
+            Casing::AlternatingCase(_) => Casing::AlternatingCase(seperator),
+            Casing::Custom { per_word, .. } => Casing::Custom { per_word: per_word.clone(), separator: seperator.as_str().into() },
+            casing => casing.clone(),
         }
     }
 
     /// Applies the casing style to the given words
     pub fn apply(&self, words: Vec<&str>) -> String {
+        self.apply_with_options(words, TITLE_CASE_STOP_WORDS, false)
+    }
+
+    /// Applies the casing style to the given words, using the given stop words
+    /// for [`Casing::TitleCase`] and leaving fully uppercase words untouched
+    /// if `preserve_all_caps` is set
+    pub(crate) fn apply_with_options<S: AsRef<str>>(&self, words: Vec<&str>, stop_words: &[S], preserve_all_caps: bool) -> String {
+        let mut out = String::new();
+        self.apply_into(&mut out, words, stop_words, preserve_all_caps);
+        out
+    }
+
+    /// Appends the cased words to the buffer, see [`Casing::apply_with_options`]
+    fn apply_into<S: AsRef<str>>(&self, out: &mut String, words: Vec<&str>, stop_words: &[S], preserve_all_caps: bool) {
+        let seperator = self.seperator_str();
+        let preserved = |word: &str| preserve_all_caps && is_all_caps(word);
+        let start = out.len();
+        out.reserve(words.iter().map(|word| word.len() + seperator.len()).sum());
+        let last = words.len().saturating_sub(1);
+        let push_seperator = |out: &mut String, i: usize| {
+            if i > 0 {
+                out.push_str(seperator);
+            }
+        };
+
         match self {
-            Casing::Lowercase(seperator) => words.join(seperator.to_string().as_str()).to_lowercase(),
-            Casing::Uppercase(seperator) => words.join(seperator.to_string().as_str()).to_uppercase(),
-            Casing::Capitalize(seperator) => words
-                .into_iter()
-                .map(|word| {
-                    let mut c = word.chars();
-                    match c.next() {
-                        None => String::new(),
-                        Some(f) => f.to_uppercase().collect::<String>() + c.as_str().to_lowercase().as_str(),
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join(seperator.to_string().as_str()),
-            Casing::CapitalizeFirst(seperator) => words
-                .into_iter()
-                .enumerate()
-                .map(|(i, word)| {
-                    if i == 0 {
-                        let mut c = word.chars();
-                        match c.next() {
-                            None => String::new(),
-                            Some(f) => f.to_uppercase().collect::<String>() + c.as_str().to_lowercase().as_str(),
+            Casing::Lowercase(_) | Casing::SnakeCase | Casing::KebabCase | Casing::DotCase | Casing::FlatCase => {
+                if words.iter().any(|word| preserved(word)) {
+                    let seperator = seperator.to_lowercase();
+                    for (i, word) in words.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(&seperator);
                         }
-                    } else {
-                        word.to_lowercase()
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join(seperator.to_string().as_str()),
-            Casing::CapitalizeLast(seperator) => words
-                .iter()
-                .enumerate()
-                .map(|(i, word)| {
-                    if i == words.len() - 1 {
-                        let mut c = word.chars();
-                        match c.next() {
-                            None => String::new(),
-                            Some(f) => f.to_uppercase().collect::<String>() + c.as_str().to_lowercase().as_str(),
+                        if preserved(word) {
+                            out.push_str(word);
+                        } else {
+                            push_lowercase(out, word);
                         }
+                    }
+                } else {
+                    join_into(out, &words, seperator);
+                    to_lowercase_in_place(out, start);
+                }
+            },
+            Casing::Uppercase(_) | Casing::ScreamingSnakeCase | Casing::ScreamingKebabCase => {
+                join_into(out, &words, seperator);
+                to_uppercase_in_place(out, start);
+            },
+            Casing::Capitalize(_)
+            | Casing::PascalCase
+            | Casing::TrainCase
+            | Casing::CapitalizedSnakeCase
+            | Casing::CapitalizedKebabCase => {
+                for (i, word) in words.iter().enumerate() {
+                    push_seperator(out, i);
+                    if preserved(word) {
+                        out.push_str(word);
                     } else {
-                        word.to_lowercase()
+                        push_capitalized(out, word);
                     }
-                })
-                .collect::<Vec<_>>()
-                .join(seperator.to_string().as_str()),
-            Casing::SnakeCase => words.join("_").to_lowercase(),
-            Casing::ScreamingSnakeCase => words.join("_").to_uppercase(),
-            Casing::CamelCase => words
-                .into_iter()
-                .enumerate()
-                .map(|(i, word)| {
-                    if i == 0 {
-                        word.to_lowercase()
+                }
+            },
+            Casing::CapitalizeFirst(_) | Casing::CapitalizeLast(_) | Casing::CamelCase => {
+                let capitalized = match self {
+                    Casing::CapitalizeFirst(_) => 0,
+                    Casing::CapitalizeLast(_) => last,
+                    _ => usize::MAX,
+                };
+                for (i, word) in words.iter().enumerate() {
+                    push_seperator(out, i);
+                    let capitalize = match self {
+                        Casing::CamelCase => i > 0,
+                        _ => i == capitalized,
+                    };
+                    if preserved(word) {
+                        out.push_str(word);
+                    } else if capitalize {
+                        push_capitalized(out, word);
                     } else {
-                        let mut c = word.chars();
-                        match c.next() {
-                            None => String::new(),
-                            Some(f) => f.to_uppercase().collect::<String>() + c.as_str().to_lowercase().as_str(),
+                        push_lowercase(out, word);
+                    }
+                }
+            },
+            Casing::TitleCase(_) => {
+                let count = words.iter().map(|word| word.split_whitespace().count()).sum::<usize>();
+                let mut index = 0;
+                for (i, word) in words.iter().enumerate() {
+                    push_seperator(out, i);
+                    for (j, token) in word.split_whitespace().enumerate() {
+                        if j > 0 {
+                            out.push(' ');
+                        }
+                        let edge = index == 0 || index + 1 == count;
+                        index += 1;
+                        if preserved(token) {
+                            out.push_str(token);
+                            continue;
+                        }
+                        let lowercase = (!edge).then(|| token.to_lowercase());
+                        match lowercase {
+                            Some(lowercase) if stop_words.iter().any(|stop| stop.as_ref() == lowercase) => out.push_str(&lowercase),
+                            _ => push_capitalized(out, token),
                         }
                     }
-                })
-                .collect::<Vec<_>>()
-                .join(""),
-            Casing::PascalCase => Casing::Capitalize(NumberSeperator::None).apply(words),
-            Casing::KebabCase => words.join("-").to_lowercase(),
-            Casing::ScreamingKebabCase => words.join("-").to_uppercase(),
+                }
+            },
+            Casing::AlternatingCase(_) => {
+                let mut upper = false;
+                for (i, word) in words.iter().enumerate() {
+                    push_seperator(out, i);
+                    if preserved(word) {
+                        out.push_str(word);
+                        continue;
+                    }
+                    for c in word.chars() {
+                        let alphabetic = c.is_alphabetic();
+                        if alphabetic && upper {
+                            out.extend(c.to_uppercase());
+                        } else {
+                            out.extend(c.to_lowercase());
+                        }
+                        upper ^= alphabetic;
+                    }
+                }
+            },
+            Casing::Custom { per_word: CustomCasing(per_word), .. } => {
+                for (i, word) in words.iter().enumerate() {
+                    push_seperator(out, i);
+                    out.push_str(&per_word(word, i));
+                }
+            },
+        }
+    }
+
+    /// Returns the seperator for the casing style without allocating
+    fn seperator_str(&self) -> &str {
+        match self {
+            Casing::Lowercase(seperator)
+            | Casing::Uppercase(seperator)
+            | Casing::Capitalize(seperator)
+            | Casing::CapitalizeFirst(seperator)
+            | Casing::CapitalizeLast(seperator)
+            | Casing::TitleCase(seperator)
+            | Casing::AlternatingCase(seperator) => seperator.as_str(),
+            Casing::SnakeCase | Casing::ScreamingSnakeCase | Casing::CapitalizedSnakeCase => "_",
+            Casing::CamelCase | Casing::PascalCase | Casing::FlatCase => "",
+            Casing::KebabCase | Casing::ScreamingKebabCase | Casing::TrainCase | Casing::CapitalizedKebabCase => "-",
+            Casing::DotCase => ".",
+            Casing::Custom { separator, .. } => separator,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// Reads the trimmed lines of the reader, skipping blank lines and `#` comments
+fn read_words(reader: impl BufRead) -> Result<Vec<Cow<'static, str>>, Error> {
+    reader
+        .lines()
+        .filter_map(|line| match line {
+            Ok(line) => {
+                let word = line.trim();
+                (!word.is_empty() && !word.starts_with('#')).then(|| Ok(Cow::Owned(word.to_string())))
+            },
+            Err(e) => Some(Err(Error::Io(e.to_string()))),
+        })
+        .collect()
+}
+
+/// Lowercases the words, keeping those without uppercase characters borrowed
+fn lowercase_words(words: &mut [Cow<'static, str>]) {
+    for word in words.iter_mut().filter(|word| word.chars().any(char::is_uppercase)) {
+        *word = Cow::Owned(word.to_lowercase());
+    }
+}
+
+/// Returns whether the word is in the list, comparing lowercased characters
+fn contains_ignoring_case(words: &[Cow<'static, str>], word: &str) -> bool {
+    let word = word.to_lowercase();
+    words.iter().any(|candidate| candidate.chars().flat_map(char::to_lowercase).eq(word.chars()))
+}
+
+/// Returns whether the word has letters and all of them are uppercase, such as
+/// an acronym
+fn is_all_caps(word: &str) -> bool {
+    word.chars().any(char::is_alphabetic) && !word.chars().any(char::is_lowercase)
+}
+
+/// Appends the words to the buffer, joined by the seperator
+fn join_into(out: &mut String, words: &[&str], seperator: &str) {
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            out.push_str(seperator);
         }
+        out.push_str(word);
+    }
+}
+
+/// Lowercases the buffer from `start`, in place when it is ASCII
+fn to_lowercase_in_place(s: &mut String, start: usize) {
+    if s[start..].is_ascii() {
+        s[start..].make_ascii_lowercase();
+    } else {
+        let lowercase = s[start..].to_lowercase();
+        s.truncate(start);
+        s.push_str(&lowercase);
+    }
+}
+
+/// Uppercases the buffer from `start`, in place when it is ASCII
+fn to_uppercase_in_place(s: &mut String, start: usize) {
+    if s[start..].is_ascii() {
+        s[start..].make_ascii_uppercase();
+    } else {
+        let uppercase = s[start..].to_uppercase();
+        s.truncate(start);
+        s.push_str(&uppercase);
+    }
+}
+
+/// Appends the lowercased word to the buffer
+fn push_lowercase(out: &mut String, word: &str) {
+    if word.is_ascii() {
+        out.extend(word.chars().map(|c| c.to_ascii_lowercase()));
+    } else {
+        out.push_str(&word.to_lowercase());
+    }
+}
+
+/// Appends the word to the buffer with its first character uppercased and the
+/// rest lowercased
+fn push_capitalized(out: &mut String, word: &str) {
+    let mut c = word.chars();
+    if let Some(f) = c.next() {
+        out.extend(f.to_uppercase());
+        push_lowercase(out, c.as_str());
     }
 }
 
-fn adjectives<'a>() -> Vec<String> {
-    ADJECTIVES.iter().map(|s| s.to_string()).collect()
+fn adjectives() -> Vec<Cow<'static, str>> {
+    ADJECTIVES.iter().map(|&s| Cow::Borrowed(s)).collect()
+}
+fn nouns() -> Vec<Cow<'static, str>> {
+    NOUNS.iter().map(|&s| Cow::Borrowed(s)).collect()
+}
+fn title_case_stop_words() -> Vec<String> {
+    TITLE_CASE_STOP_WORDS.iter().map(|s| s.to_string()).collect()
 }
-fn nouns<'a>() -> Vec<String> {
-    NOUNS.iter().map(|s| s.to_string()).collect()
+fn adjective_count() -> usize {
+    1
+}
+fn max_reroll_attempts() -> usize {
+    1000
+}
+
+/// The largest space of names for which [`Generator::into_exhaustive`]
+/// enumerates numbers as well as words
+const EXHAUSTIVE_LIMIT: u128 = 1 << 20;
+
+/// A callback which transforms each adjective and noun, see
+/// [`GeneratorBuilder::transform`]
+pub type WordTransform = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+#[derive(Clone)]
+struct Transform(WordTransform);
+
+impl fmt::Debug for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Transform(..)")
+    }
 }
 
 /// All of the errors for this crate.
-#[derive(Debug, thiserror::Error)]
+#[derive(Eq, PartialEq, Debug)]
 pub enum Error {
     /// Uninitialized field
-    #[error("uninitialized field: {0}")]
     UninitializedField(&'static str),
     /// Custom validation error
-    #[error("validation error: {0}")]
     ValidationError(String),
     /// Adjectives is empty
-    #[error("adjectives must not be empty")]
     AdjectivesEmpty,
     /// Nouns is empty
-    #[error("nouns must not be empty")]
     NounsEmpty,
     /// The iterator was empty
-    #[error("the iterator was empty")]
     EmptyIterator,
+    /// A numbered naming strategy was given zero digits
+    ZeroDigits,
+    /// A numbered naming strategy was given more than [`MAX_DIGITS`] digits
+    TooManyDigits(usize),
+    /// The adjective weights do not match the adjectives
+    AdjectiveWeightsMismatch,
+    /// The noun weights do not match the nouns
+    NounWeightsMismatch,
+    /// The minimum of a length range is greater than its maximum
+    InvalidLengthRange(usize, usize),
+    /// The minimum of a number range is greater than its maximum
+    InvalidNumberRange(u64, u64),
+    /// A casing style could not be parsed
+    UnknownCasing(String),
+    /// A naming strategy could not be parsed
+    UnknownNaming(String),
+    /// The generator ran out of distinct names
+    Exhausted,
+    /// The generator rejected `max_reroll_attempts` names in a row
+    RerollLimitExceeded,
+    /// A template could not be parsed
+    InvalidTemplate(String),
+    /// A word contains the seperator joining the words
+    SeparatorInWord(String),
+    /// `distinct_words` was requested with the same single adjective and noun
+    IndistinctWords,
+    /// A words file could not be read or parsed
+    WordsFile(String),
+    /// A word list could not be read
+    Io(String),
+    /// A [`Name::EncodedSuffix`] was given an empty alphabet
+    EmptyAlphabet,
+    /// A [`Name::EncodedSuffix`] alphabet contains a symbol more than once
+    DuplicateSymbol(char),
+    /// The generator can produce fewer names than `min_combinations`
+    TooFewCombinations(u128, u128),
+    /// A [`Name::VariableNumbered`] has a minimum digit count above its maximum
+    InvalidDigitRange(usize, usize),
+    /// A [`Structure::DoubleNoun`] has fewer than two different nouns
+    TooFewNouns,
+    /// A [`Length`] which no name can satisfy, given the shortest and longest
+    /// names the generator can produce
+    UnreachableLength(Length, usize, usize),
+    /// A naming strategy needs the `std` feature, such as
+    /// [`Name::Timestamped`] which reads the system clock
+    RequiresStd,
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UninitializedField(field) => write!(f, "uninitialized field: {}", field),
+            Error::ValidationError(message) => write!(f, "validation error: {}", message),
+            Error::AdjectivesEmpty => f.write_str("adjectives must not be empty"),
+            Error::NounsEmpty => f.write_str("nouns must not be empty"),
+            Error::EmptyIterator => f.write_str("the iterator was empty"),
+            Error::ZeroDigits => f.write_str("the number of digits must be at least 1"),
+            Error::TooManyDigits(x) => write!(f, "the number of digits must be at most {}, got {}", MAX_DIGITS, x),
+            Error::AdjectiveWeightsMismatch => f.write_str("adjective weights must match the number of adjectives"),
+            Error::NounWeightsMismatch => f.write_str("noun weights must match the number of nouns"),
+            Error::InvalidLengthRange(min, max) => write!(f, "length range {}..={} is empty", min, max),
+            Error::InvalidNumberRange(min, max) => write!(f, "number range {}..={} is empty", min, max),
+            Error::UnknownCasing(casing) => write!(f, "unknown casing: {}", casing),
+            Error::UnknownNaming(naming) => write!(f, "unknown naming: {}", naming),
+            Error::Exhausted => f.write_str("the generator ran out of distinct names"),
+            Error::RerollLimitExceeded => f.write_str("the generator gave up after too many rerolls"),
+            Error::InvalidTemplate(message) => write!(f, "invalid template: {}", message),
+            Error::SeparatorInWord(word) => write!(f, "word contains the separator: {}", word),
+            Error::IndistinctWords => f.write_str("distinct words are impossible with the same single adjective and noun"),
+            Error::WordsFile(message) => write!(f, "invalid words file: {}", message),
+            Error::Io(message) => write!(f, "failed to read words: {}", message),
+            Error::EmptyAlphabet => f.write_str("the alphabet must not be empty"),
+            Error::DuplicateSymbol(symbol) => write!(f, "the alphabet contains {:?} more than once", symbol),
+            Error::TooFewNouns => f.write_str("two different nouns are needed for double nouns"),
+            Error::UnreachableLength(length, shortest, longest) => {
+                write!(f, "no name can satisfy {:?}, names are between {} and {} characters long", length, shortest, longest)
+            },
+            Error::RequiresStd => f.write_str("the naming strategy requires the std feature"),
+            Error::InvalidDigitRange(min, max) => write!(f, "the minimum of {} digits exceeds the maximum of {}", min, max),
+            Error::TooFewCombinations(combinations, min) => {
+                write!(f, "the generator can produce {} names, fewer than the minimum of {}", combinations, min)
+            },
+        }
+    }
 }
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
 impl From<UninitializedFieldError> for Error {
     fn from(e: UninitializedFieldError) -> Self { Self::UninitializedField(e.field_name()) }
 }
@@ -346,66 +1199,180 @@ impl From<String> for Error {
     fn from(s: String) -> Self { Self::ValidationError(s) }
 }
 
+#[cfg(feature = "words-file")]
 #[derive(Deserialize)]
-struct GeneratorJson {
-    #[serde(default = "adjectives")]
+struct WordsFile {
     adjectives: Vec<String>,
-    #[serde(default = "nouns")]
     nouns: Vec<String>,
+}
+
+/// The configuration of a [`Generator`], without its RNG
+///
+/// A [`GeneratorConfig`] serializes to the same fields as a [`Generator`], and
+/// can be shared and stored independently of the RNG, which is only chosen by
+/// [`GeneratorConfig::with_rng`]. Unset fields take the same defaults as the
+/// [`GeneratorBuilder`]. The [`GeneratorBuilder::transform`] callback is not
+/// part of the configuration.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GeneratorConfig {
+    /// The adjectives, see [`GeneratorBuilder::adjectives`]
+    #[serde(default = "adjectives")]
+    pub adjectives: Vec<Cow<'static, str>>,
+    /// The nouns, see [`GeneratorBuilder::nouns`]
+    #[serde(default = "nouns")]
+    pub nouns: Vec<Cow<'static, str>>,
+    /// The naming strategy
     #[serde(default)]
-    naming: Name,
+    pub naming: Name,
+    /// The casing
     #[serde(default)]
-    casing: Casing,
+    pub casing: Casing,
+    /// The maximum length of the generated name
     #[serde(default)]
-    length: Length,
+    pub length: Length,
+    /// See [`GeneratorBuilder::max_reroll_attempts`]
+    #[serde(default = "max_reroll_attempts")]
+    pub max_reroll_attempts: usize,
+    /// See [`GeneratorBuilder::title_case_stop_words`]
+    #[serde(default = "title_case_stop_words")]
+    pub title_case_stop_words: Vec<String>,
+    /// See [`GeneratorBuilder::number_position`]
+    #[serde(default)]
+    pub number_position: NumberPosition,
+    /// See [`GeneratorBuilder::adjective_count`]
+    #[serde(default = "adjective_count")]
+    pub adjective_count: usize,
+    /// See [`GeneratorBuilder::distinct_words`]
+    #[serde(default)]
+    pub distinct_words: bool,
+    /// See [`GeneratorBuilder::adjective_weights`]
+    #[serde(default)]
+    pub adjective_weights: Option<Vec<u32>>,
+    /// See [`GeneratorBuilder::noun_weights`]
+    #[serde(default)]
+    pub noun_weights: Option<Vec<u32>>,
+    /// See [`GeneratorBuilder::alliterative`]
+    #[serde(default)]
+    pub alliterative: bool,
+    /// See [`GeneratorBuilder::min_word_len`]
+    #[serde(default)]
+    pub min_word_len: Option<usize>,
+    /// See [`GeneratorBuilder::max_word_len`]
+    #[serde(default)]
+    pub max_word_len: Option<usize>,
+    /// See [`GeneratorBuilder::word_separator`]
+    #[serde(default)]
+    pub word_separator: Option<NumberSeperator>,
+    /// See [`GeneratorBuilder::dedup_dictionaries`]
+    #[serde(default)]
+    pub dedup_dictionaries: bool,
+    /// See [`GeneratorBuilder::max_syllables`]
+    #[serde(default)]
+    pub max_syllables: Option<usize>,
+    /// See [`GeneratorBuilder::reject_separator_in_words`]
+    #[serde(default)]
+    pub reject_separator_in_words: bool,
+    /// See [`GeneratorBuilder::ascii_only`]
+    #[serde(default)]
+    pub ascii_only: bool,
+    /// See [`GeneratorBuilder::structure`]
+    #[serde(default)]
+    pub structure: Structure,
+    /// See [`GeneratorBuilder::blocklist`]
+    #[serde(default)]
+    pub blocklist: BTreeSet<String>,
+    /// See [`GeneratorBuilder::preserve_all_caps_words`]
+    #[serde(default)]
+    pub preserve_all_caps_words: bool,
+    /// See [`GeneratorBuilder::min_combinations`]
+    #[serde(default)]
+    pub min_combinations: Option<u128>,
+    /// See [`GeneratorBuilder::normalize_case`]
+    #[serde(default)]
+    pub normalize_case: bool,
 }
-impl GeneratorJson {
-    fn thread_rng(self) -> Generator<ThreadRng> {
-        Generator {
-            adjectives: self.adjectives,
-            nouns: self.nouns,
-            naming: self.naming,
-            casing: self.casing,
-            length: self.length,
-            rng: rand::thread_rng(),
-        }
-    }
 
-    fn os_rng(self) -> Generator<OsRng> {
-        Generator {
-            adjectives: self.adjectives,
-            nouns: self.nouns,
-            naming: self.naming,
-            casing: self.casing,
-            length: self.length,
-            rng: OsRng,
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        GeneratorConfig {
+            adjectives: adjectives(),
+            nouns: nouns(),
+            naming: Name::default(),
+            casing: Casing::default(),
+            length: Length::default(),
+            max_reroll_attempts: max_reroll_attempts(),
+            title_case_stop_words: title_case_stop_words(),
+            number_position: NumberPosition::default(),
+            adjective_count: adjective_count(),
+            distinct_words: false,
+            adjective_weights: None,
+            noun_weights: None,
+            alliterative: false,
+            min_word_len: None,
+            max_word_len: None,
+            word_separator: None,
+            dedup_dictionaries: false,
+            max_syllables: None,
+            reject_separator_in_words: false,
+            ascii_only: false,
+            structure: Structure::default(),
+            blocklist: BTreeSet::new(),
+            preserve_all_caps_words: false,
+            min_combinations: None,
+            normalize_case: false,
         }
     }
+}
 
-    fn std_rng(self) -> Generator<StdRng> {
-        use rand::SeedableRng;
-
-        Generator {
-            adjectives: self.adjectives,
-            nouns: self.nouns,
-            naming: self.naming,
-            casing: self.casing,
-            length: self.length,
-            rng: StdRng::from_entropy(),
+impl GeneratorConfig {
+    /// Builds a [`Generator`] from the configuration using the given RNG
+    ///
+    /// The configuration is validated and filtered the same way as
+    /// [`GeneratorBuilder::build`], so the same errors are returned.
+    pub fn with_rng<R: Rng + Clone>(self, rng: R) -> Result<Generator<R>, Error> {
+        let mut builder = GeneratorBuilder::default();
+        builder
+            .adjectives(self.adjectives)
+            .nouns(self.nouns)
+            .naming(self.naming)
+            .casing(self.casing)
+            .length(self.length)
+            .max_reroll_attempts(self.max_reroll_attempts)
+            .title_case_stop_words(self.title_case_stop_words)
+            .number_position(self.number_position)
+            .adjective_count(self.adjective_count)
+            .distinct_words(self.distinct_words)
+            .alliterative(self.alliterative)
+            .dedup_dictionaries(self.dedup_dictionaries)
+            .reject_separator_in_words(self.reject_separator_in_words)
+            .ascii_only(self.ascii_only)
+            .structure(self.structure)
+            .blocklist(self.blocklist)
+            .preserve_all_caps_words(self.preserve_all_caps_words)
+            .normalize_case(self.normalize_case)
+            .rng(rng);
+        if let Some(weights) = self.adjective_weights {
+            builder.adjective_weights(weights);
         }
-    }
-
-    fn small_rng(self) -> Generator<SmallRng> {
-        use rand::SeedableRng;
-
-        Generator {
-            adjectives: self.adjectives,
-            nouns: self.nouns,
-            naming: self.naming,
-            casing: self.casing,
-            length: self.length,
-            rng: SmallRng::from_entropy(),
+        if let Some(weights) = self.noun_weights {
+            builder.noun_weights(weights);
+        }
+        if let Some(len) = self.min_word_len {
+            builder.min_word_len(len);
+        }
+        if let Some(len) = self.max_word_len {
+            builder.max_word_len(len);
+        }
+        if let Some(separator) = self.word_separator {
+            builder.word_separator(separator);
+        }
+        if let Some(syllables) = self.max_syllables {
+            builder.max_syllables(syllables);
+        }
+        if let Some(min) = self.min_combinations {
+            builder.min_combinations(min);
         }
+        builder.build()
     }
 }
 
@@ -416,15 +1383,26 @@ impl GeneratorJson {
 /// a naming strategy (with or without a number appended).
 /// 
 /// To generate a [`Generator`], use [`GeneratorBuilder`], view the [examples](crate#examples) for more information.
+///
+/// A [`Generator`] serializes its configuration without the RNG, and can be
+/// deserialized back into a generator using any of the entropy-seeded RNGs. To
+/// choose the RNG separately, deserialize a [`GeneratorConfig`] instead.
+///
+/// A [`Generator`] is [`Send`] and [`Sync`] whenever its RNG is, so a
+/// `Generator<ThreadRng>` cannot be shared across threads. Use a
+/// [`SendGenerator`], seeded with [`GeneratorBuilder::seed`], or a
+/// `Generator<SmallRng>` instead.
 #[derive(Serialize, Builder, Clone, Debug)]
-#[builder(build_fn(validate = "Self::validate", error = "Error"))]
+#[builder(no_std, build_fn(private, name = "build_unfiltered", validate = "Self::validate", error = "Error"))]
 pub struct Generator<R: Rng> {
-    /// A slice of adjective words
-    #[builder(setter(into), default = "adjectives()")]
-    pub adjectives: Vec<String>,
-    /// A slice of noun words
-    #[builder(setter(into), default = "nouns()")]
-    nouns: Vec<String>,
+    /// A slice of adjective words, borrowing the built-in [`ADJECTIVES`] by
+    /// default, see [`GeneratorBuilder::adjectives`]
+    #[builder(setter(custom), default = "adjectives()")]
+    adjectives: Vec<Cow<'static, str>>,
+    /// A slice of noun words, borrowing the built-in [`NOUNS`] by default, see
+    /// [`GeneratorBuilder::nouns`]
+    #[builder(setter(custom), default = "nouns()")]
+    nouns: Vec<Cow<'static, str>>,
     /// A naming strategy
     #[builder(setter(into), default)]
     #[serde(default)]
@@ -437,42 +1415,495 @@ pub struct Generator<R: Rng> {
     #[builder(setter(into), default)]
     #[serde(default)]
     length: Length,
+    /// The maximum number of attempts made to satisfy the [`Length`] before
+    /// giving up and returning `None`, defaults to `1000`
+    #[builder(default = "max_reroll_attempts()")]
+    #[serde(default = "max_reroll_attempts")]
+    max_reroll_attempts: usize,
+    /// The words which [`Casing::TitleCase`] leaves lowercase, defaults to
+    /// [`TITLE_CASE_STOP_WORDS`]
+    #[builder(setter(into), default = "title_case_stop_words()")]
+    #[serde(default = "title_case_stop_words")]
+    title_case_stop_words: Vec<String>,
+    /// Where the number of a numbered naming strategy is placed, defaults to
+    /// [`NumberPosition::Suffix`]
+    #[builder(default)]
+    #[serde(default)]
+    number_position: NumberPosition,
+    /// The number of adjectives placed before the noun, defaults to `1`
+    ///
+    /// Adjectives are distinct when the adjective list is long enough, otherwise
+    /// they are drawn independently and may repeat. A count of `0` yields only the
+    /// noun.
+    #[builder(default = "adjective_count()")]
+    #[serde(default = "adjective_count")]
+    adjective_count: usize,
+    /// Whether the noun must differ from the adjectives, defaults to `false`
+    ///
+    /// The adjectives are rerolled up to `max_reroll_attempts` times when every
+    /// noun matches one of them, after which no name is generated. Building fails
+    /// with [`Error::IndistinctWords`] when the adjectives and nouns are the same
+    /// single word.
+    #[builder(default)]
+    #[serde(default)]
+    distinct_words: bool,
+    /// Relative weights for choosing each adjective, which must match the length
+    /// of the adjectives. With weights, multiple adjectives are drawn independently
+    /// and may repeat.
+    #[builder(setter(into, strip_option), default)]
+    #[serde(default)]
+    adjective_weights: Option<Vec<u32>>,
+    /// Relative weights for choosing each noun, which must match the length of the
+    /// nouns
+    #[builder(setter(into, strip_option), default)]
+    #[serde(default)]
+    noun_weights: Option<Vec<u32>>,
+    /// Whether the noun must start with the same letter as the first adjective,
+    /// defaults to `false`
+    ///
+    /// The adjective is rerolled up to `max_reroll_attempts` times when no noun
+    /// matches, after which no name is generated.
+    #[builder(default)]
+    #[serde(default)]
+    alliterative: bool,
+    /// The minimum number of characters of each adjective and noun, shorter words
+    /// are removed when building
+    #[builder(setter(strip_option), default)]
+    #[serde(default)]
+    min_word_len: Option<usize>,
+    /// The maximum number of characters of each adjective and noun, longer words
+    /// are removed when building
+    #[builder(setter(strip_option), default)]
+    #[serde(default)]
+    max_word_len: Option<usize>,
+    /// The separator joining the adjectives and noun, overriding the one held by
+    /// the [`Casing`] variants which take a [`NumberSeperator`]
+    ///
+    /// The separator of the number is still set by the [`Name`].
+    #[builder(setter(into, strip_option), default)]
+    #[serde(default)]
+    word_separator: Option<NumberSeperator>,
+    /// Whether duplicate adjectives and nouns are removed when building, keeping
+    /// the first copy and its weight, defaults to `false`
+    ///
+    /// Duplicated words are otherwise more likely to be chosen, so removing them
+    /// changes the selection probabilities.
+    #[builder(default)]
+    #[serde(default)]
+    dedup_dictionaries: bool,
+    /// The maximum number of syllables of the adjectives and noun combined, as
+    /// estimated by [`count_syllables`]
+    ///
+    /// The adjectives are rerolled up to `max_reroll_attempts` times when no noun
+    /// fits, after which no name is generated.
+    #[builder(setter(strip_option), default)]
+    #[serde(default)]
+    max_syllables: Option<usize>,
+    /// Whether building fails with [`Error::SeparatorInWord`] when an adjective or
+    /// noun contains the seperator joining the words, defaults to `false`
+    ///
+    /// Such words make names ambiguous, for example to [`Generator::matches`].
+    #[builder(default)]
+    #[serde(default)]
+    reject_separator_in_words: bool,
+    /// Whether adjectives and nouns containing non-ASCII characters are removed
+    /// when building, defaults to `false`
+    #[builder(default)]
+    #[serde(default)]
+    ascii_only: bool,
+    /// The number of names generated, used by [`Name::Sequential`]
+    #[builder(setter(skip))]
+    #[serde(skip)]
+    sequence: u64,
+    /// The number of names drawn for the last call to `next`, see
+    /// [`Generator::last_attempt_count`]
+    #[builder(setter(skip))]
+    #[serde(skip)]
+    attempts: usize,
+    /// Which words make up the name, defaults to [`Structure::AdjectiveNoun`]
+    ///
+    /// `distinct_words` and `alliterative` only apply to [`Structure::AdjectiveNoun`].
+    #[builder(default)]
+    #[serde(default)]
+    structure: Structure,
+    /// Lowercase words and names which are never generated, see
+    /// [`GeneratorBuilder::blocklist`]
+    #[builder(setter(custom), default)]
+    #[serde(default)]
+    blocklist: BTreeSet<String>,
+    /// Whether fully uppercase words, such as `"API"`, are left untouched by the
+    /// [`Casing`], defaults to `false`
+    ///
+    /// A word is fully uppercase when it has letters and none of them are
+    /// lowercase. The other words are cased as usual: the lowercase styles, such as
+    /// [`Casing::Lowercase`] and [`Casing::SnakeCase`], still lowercase their
+    /// seperator, [`Casing::TitleCase`] checks each whitespace-separated part of a
+    /// word on its own, and [`Casing::AlternatingCase`] skips kept words without
+    /// advancing the alternation. The uppercase styles leave such words unchanged
+    /// regardless. Affixes of [`Name::Prefixed`] and [`Name::Suffixed`] are kept the
+    /// same way.
+    #[builder(default)]
+    #[serde(default)]
+    preserve_all_caps_words: bool,
+    /// The fewest distinct names the generator may produce, as counted by
+    /// [`Generator::combinations`] after any build-time filtering
+    ///
+    /// [`GeneratorBuilder::build`] fails with [`Error::TooFewCombinations`] below
+    /// it, guarding against a name space shrunk too far by filters.
+    #[builder(setter(strip_option), default)]
+    #[serde(default)]
+    min_combinations: Option<u128>,
+    /// Whether adjectives and nouns are lowercased when building, defaults to
+    /// `false`, so that the [`Casing`] alone decides the case of the names
+    ///
+    /// Words are lowercased before any other filtering, so mixed-case duplicates
+    /// are removed by `dedup_dictionaries`. This changes the output for fully
+    /// uppercase custom words, such as `"API"`, which `preserve_all_caps_words` no
+    /// longer keeps.
+    #[builder(default)]
+    #[serde(default)]
+    normalize_case: bool,
+    /// A callback applied to each adjective and noun before the casing, see
+    /// [`GeneratorBuilder::transform`]
+    #[builder(setter(custom), default)]
+    #[serde(skip)]
+    transform: Option<Transform>,
     #[serde(default)]
     #[serde(skip)]
     /// The random number generator
     rng: R
 }
 
+#[cfg(feature = "std")]
 impl<'de> Deserialize<'de> for Generator<ThreadRng> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de>
     {
-        Ok(GeneratorJson::deserialize(deserializer)?.thread_rng())
+        GeneratorConfig::deserialize(deserializer)?.with_rng(rand::thread_rng()).map_err(serde::de::Error::custom)
     }
 }
+#[cfg(feature = "std")]
 impl<'de> Deserialize<'de> for Generator<OsRng> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de>
     {
-        Ok(GeneratorJson::deserialize(deserializer)?.os_rng())
+        GeneratorConfig::deserialize(deserializer)?.with_rng(OsRng).map_err(serde::de::Error::custom)
     }
 }
+#[cfg(feature = "std")]
 impl<'de> Deserialize<'de> for Generator<StdRng> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de>
     {
-        Ok(GeneratorJson::deserialize(deserializer)?.std_rng())
+        GeneratorConfig::deserialize(deserializer)?.with_rng(StdRng::from_entropy()).map_err(serde::de::Error::custom)
     }
 }
+#[cfg(feature = "std")]
 impl<'de> Deserialize<'de> for Generator<SmallRng> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de>
     {
-        Ok(GeneratorJson::deserialize(deserializer)?.small_rng())
+        GeneratorConfig::deserialize(deserializer)?.with_rng(SmallRng::from_entropy()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The entropy-seeded RNG used by an [`AnyGenerator`]
+#[cfg(feature = "std")]
+#[derive(Serialize, Deserialize, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum RngKind {
+    /// A [`ThreadRng`], serialized as `"thread"`
+    #[default]
+    Thread,
+    /// An [`OsRng`], serialized as `"os"`
+    Os,
+    /// A [`StdRng`], serialized as `"std"`
+    Std,
+    /// A [`SmallRng`], serialized as `"small"`
+    Small,
+}
+
+/// A [`Generator`] whose RNG is chosen at runtime
+///
+/// When deserialized, the RNG is selected by the optional `"rng"` field (see
+/// [`RngKind`]), defaulting to a [`ThreadRng`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyGenerator {
+    /// A generator using a [`ThreadRng`]
+    Thread(Generator<ThreadRng>),
+    /// A generator using an [`OsRng`]
+    Os(Generator<OsRng>),
+    /// A generator using a [`StdRng`]
+    Std(Generator<StdRng>),
+    /// A generator using a [`SmallRng`]
+    Small(Generator<SmallRng>),
+}
+
+#[cfg(feature = "std")]
+impl AnyGenerator {
+    /// Returns which RNG the generator uses
+    pub fn rng_kind(&self) -> RngKind {
+        match self {
+            AnyGenerator::Thread(_) => RngKind::Thread,
+            AnyGenerator::Os(_) => RngKind::Os,
+            AnyGenerator::Std(_) => RngKind::Std,
+            AnyGenerator::Small(_) => RngKind::Small,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de> Deserialize<'de> for AnyGenerator {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_map(AnyGeneratorVisitor)
+    }
+}
+
+#[cfg(feature = "std")]
+struct AnyGeneratorVisitor;
+
+#[cfg(feature = "std")]
+impl<'de> Visitor<'de> for AnyGeneratorVisitor {
+    type Value = AnyGenerator;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a generator configuration")
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<AnyGenerator, A::Error>
+        where A: MapAccess<'de>
+    {
+        let mut rng = RngKind::default();
+        let config = GeneratorConfig::deserialize(MapAccessDeserializer::new(WithoutRng { map, rng: &mut rng }))?;
+        match rng {
+            RngKind::Thread => config.with_rng(rand::thread_rng()).map(AnyGenerator::Thread),
+            RngKind::Os => config.with_rng(OsRng).map(AnyGenerator::Os),
+            RngKind::Std => config.with_rng(StdRng::from_entropy()).map(AnyGenerator::Std),
+            RngKind::Small => config.with_rng(SmallRng::from_entropy()).map(AnyGenerator::Small),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+
+/// The map of an [`AnyGenerator`] with its `"rng"` entry taken out, leaving
+/// the fields of a [`GeneratorConfig`]
+///
+/// The entries are passed through one at a time, rather than buffered as
+/// `#[serde(flatten)]` does, which would not support the `u128` of
+/// `min_combinations`.
+#[cfg(feature = "std")]
+struct WithoutRng<'a, A> {
+    map: A,
+    rng: &'a mut RngKind,
+}
+
+#[cfg(feature = "std")]
+impl<'de, A> MapAccess<'de> for WithoutRng<'_, A>
+    where A: MapAccess<'de>
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>
+        where K: DeserializeSeed<'de>
+    {
+        while let Some(key) = self.map.next_key::<String>()? {
+            if key == "rng" {
+                *self.rng = self.map.next_value()?;
+            } else {
+                return seed.deserialize(StringDeserializer::new(key)).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, A::Error>
+        where V: DeserializeSeed<'de>
+    {
+        self.map.next_value_seed(seed)
+    }
+
+}
+
+#[cfg(feature = "std")]
+impl Iterator for AnyGenerator {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            AnyGenerator::Thread(generator) => generator.next(),
+            AnyGenerator::Os(generator) => generator.next(),
+            AnyGenerator::Std(generator) => generator.next(),
+            AnyGenerator::Small(generator) => generator.next(),
+        }
+    }
+}
+
+impl<R: Rng + Clone> GeneratorBuilder<R> {
+    /// Builds a new [`Generator`], removing any words excluded by the
+    /// configured options
+    pub fn build(&self) -> Result<Generator<R>, Error> {
+        let mut generator = self.build_unfiltered()?;
+        generator.filter_words()?;
+        if let Some(min) = generator.min_combinations {
+            let combinations = generator.combinations();
+            if combinations < min {
+                return Err(Error::TooFewCombinations(combinations, min));
+            }
+        }
+        generator.check_length()?;
+        Ok(generator)
+    }
+
+    #[cfg(feature = "words-file")]
+    /// Returns a builder with the adjectives and nouns read from a single file
+    /// of the form `{ "adjectives": [...], "nouns": [...] }`
+    ///
+    /// Files with a `.toml` extension are parsed as TOML and any other file as
+    /// JSON. Fails with [`Error::WordsFile`] if the file cannot be read or
+    /// parsed, and with [`Error::AdjectivesEmpty`] or [`Error::NounsEmpty`] if
+    /// either list is empty.
+    pub fn from_words_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let invalid = |e: &dyn fmt::Display| Error::WordsFile(format!("{}: {}", path.display(), e));
+        let contents = fs::read_to_string(path).map_err(|e| invalid(&e))?;
+        let words: WordsFile = if path.extension().is_some_and(|extension| extension == "toml") {
+            toml::from_str(&contents).map_err(|e| invalid(&e))?
+        } else {
+            serde_json::from_str(&contents).map_err(|e| invalid(&e))?
+        };
+        if words.adjectives.is_empty() {
+            return Err(Error::AdjectivesEmpty);
+        }
+        if words.nouns.is_empty() {
+            return Err(Error::NounsEmpty);
+        }
+
+        let mut builder = Self::default();
+        builder.adjectives(words.adjectives).nouns(words.nouns);
+        Ok(builder)
     }
 }
 
 impl<R: Rng> GeneratorBuilder<R> {
+    /// Replaces the adjectives and nouns with the built-in lists of a locale,
+    /// defaults to [`Locale::En`]
+    pub fn locale(&mut self, locale: Locale) -> &mut Self {
+        self.adjectives(locale.adjectives().iter().copied()).nouns(locale.nouns().iter().copied())
+    }
+
+    /// Sets the adjectives, which may be owned `String`s or borrowed
+    /// `&'static str`s, defaults to [`ADJECTIVES`]
+    pub fn adjectives(&mut self, adjectives: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> &mut Self {
+        self.adjectives = Some(adjectives.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the nouns, which may be owned `String`s or borrowed
+    /// `&'static str`s, defaults to [`NOUNS`]
+    pub fn nouns(&mut self, nouns: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> &mut Self {
+        self.nouns = Some(nouns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Configures Docker-style names, such as `"rusty-nail"`: two lowercase
+    /// words joined by `-`, without a number or length limit
+    pub fn preset_docker(&mut self) -> &mut Self {
+        self.naming = Some(Name::Plain);
+        self.casing = Some(Casing::Lowercase(NumberSeperator::Dash));
+        self.length = Some(Length::None);
+        self
+    }
+
+    /// Configures Heroku-style names, such as `"rusty-nail-4213"`: two
+    /// lowercase words and a four digit number joined by `-`, without a length
+    /// limit
+    pub fn preset_heroku(&mut self) -> &mut Self {
+        self.naming = Some(Name::Numbered(4, NumberSeperator::Dash));
+        self.casing = Some(Casing::Lowercase(NumberSeperator::Dash));
+        self.length = Some(Length::None);
+        self
+    }
+
+    /// Configures Kubernetes-style names, such as `"rusty-nail"`: lowercase
+    /// words joined by `-` and truncated to the 63 characters of a DNS label
+    ///
+    /// The built-in words are DNS-safe; with custom words, see
+    /// [`Generator::dns_label`].
+    pub fn preset_k8s(&mut self) -> &mut Self {
+        self.naming = Some(Name::Plain);
+        self.casing = Some(Casing::KebabCase);
+        self.length = Some(Length::Truncate(63));
+        self
+    }
+
+    /// Sets the naming strategy from a string such as `"numbered:4:-"`, see
+    /// [`Name::from_str`] for the forms accepted
+    ///
+    /// Fails with [`Error::UnknownNaming`] on a malformed string, leaving the
+    /// naming strategy unchanged.
+    pub fn naming_str(&mut self, naming: &str) -> Result<&mut Self, Error> {
+        self.naming = Some(naming.parse()?);
+        Ok(self)
+    }
+
+    /// Appends to the adjectives, starting from the built-in [`ADJECTIVES`] if
+    /// none have been set
+    pub fn add_adjectives(&mut self, adjectives: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> &mut Self {
+        self.adjectives.get_or_insert_with(crate::adjectives).extend(adjectives.into_iter().map(Into::into));
+        self
+    }
+
+    /// Appends to the nouns, starting from the built-in [`NOUNS`] if none have
+    /// been set
+    pub fn add_nouns(&mut self, nouns: impl IntoIterator<Item = impl Into<Cow<'static, str>>>) -> &mut Self {
+        self.nouns.get_or_insert_with(crate::nouns).extend(nouns.into_iter().map(Into::into));
+        self
+    }
+
+    #[cfg(feature = "std")]
+    /// Sets the adjectives to the lines of the reader, trimmed of whitespace,
+    /// skipping blank lines and lines starting with `#`
+    ///
+    /// The lines are read one at a time rather than reading the whole input
+    /// first. Fails with [`Error::Io`] if reading fails, leaving the adjectives
+    /// unchanged.
+    pub fn adjectives_from_reader(&mut self, reader: impl BufRead) -> Result<&mut Self, Error> {
+        self.adjectives = Some(read_words(reader)?);
+        Ok(self)
+    }
+
+    #[cfg(feature = "std")]
+    /// Sets the nouns to the lines of the reader, see
+    /// [`GeneratorBuilder::adjectives_from_reader`]
+    pub fn nouns_from_reader(&mut self, reader: impl BufRead) -> Result<&mut Self, Error> {
+        self.nouns = Some(read_words(reader)?);
+        Ok(self)
+    }
+
+    /// Sets words and names which are never generated, compared ignoring case
+    ///
+    /// Blocked adjectives and nouns are removed when building. A generated name
+    /// is rerolled, up to `max_reroll_attempts` times, if the whole name or any
+    /// of its alphanumeric runs is blocked, which catches blocked combinations
+    /// and affixes as well as words joined by a seperator.
+    pub fn blocklist(&mut self, blocklist: impl IntoIterator<Item = String>) -> &mut Self {
+        self.blocklist = Some(blocklist.into_iter().map(|word| word.to_lowercase()).collect());
+        self
+    }
+
+    /// Sets a callback which is applied to each adjective and noun before the
+    /// [`Casing`], such as stripping vowels
+    ///
+    /// The callback is not serialized, so a deserialized generator has none.
+    pub fn transform(&mut self, transform: Option<WordTransform>) -> &mut Self {
+        self.transform = Some(transform.map(Transform));
+        self
+    }
+
     fn validate(&self) -> Result<(), Error> {
         if let Some(adjectives) = &self.adjectives {
             if adjectives.is_empty() {
@@ -484,97 +1915,1525 @@ impl<R: Rng> GeneratorBuilder<R> {
                 return Err(Error::NounsEmpty);
             }
         }
+        let adjectives = self.adjectives.as_ref().map_or(ADJECTIVES.len(), Vec::len);
+        if let Some(Some(weights)) = &self.adjective_weights {
+            if weights.len() != adjectives {
+                return Err(Error::AdjectiveWeightsMismatch);
+            }
+            WeightedIndex::new(weights).map_err(|e| Error::ValidationError(e.to_string()))?;
+        }
+        let nouns = self.nouns.as_ref().map_or(NOUNS.len(), Vec::len);
+        if let Some(Some(weights)) = &self.noun_weights {
+            if weights.len() != nouns {
+                return Err(Error::NounWeightsMismatch);
+            }
+            WeightedIndex::new(weights).map_err(|e| Error::ValidationError(e.to_string()))?;
+        }
+        if let Some(Length::Range(min, max)) = self.length {
+            if min > max {
+                return Err(Error::InvalidLengthRange(min, max));
+            }
+        }
+        if let Some(naming) = &self.naming {
+            naming.validate()?;
+        }
+        if self.distinct_words == Some(true)
+            && self.structure.unwrap_or_default() == Structure::AdjectiveNoun
+            && self.adjective_count.unwrap_or_else(adjective_count) > 0
+        {
+            if let (Some([adjective]), Some([noun])) = (self.adjectives.as_deref(), self.nouns.as_deref()) {
+                if adjective == noun {
+                    return Err(Error::IndistinctWords);
+                }
+            }
+        }
         Ok(())
     }
 }
 
-impl Default for Generator<ThreadRng> {
-    fn default() -> Self {
-        Self {
-            adjectives: adjectives(),
-            nouns: nouns(),
-            naming: Name::Plain,
-            casing: Casing::Lowercase(NumberSeperator::Dash),
-            length: Length::None,
-            rng: rand::thread_rng(),
-        }
+/// A [`Generator`] which is always [`Send`] and [`Sync`], built with
+/// [`GeneratorBuilder::seed`]
+pub type SendGenerator = Generator<StdRng>;
+
+impl GeneratorBuilder<StdRng> {
+    /// Seeds a [`StdRng`] with the given value, so that the same seed always
+    /// yields the same sequence of names
+    ///
+    /// The resulting [`SendGenerator`] can be moved to, or shared between,
+    /// threads.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.rng(StdRng::seed_from_u64(seed))
     }
 }
-impl Default for Generator<OsRng> {
-    fn default() -> Self {
+
+impl<R: Rng> Generator<R> {
+    fn with_rng(rng: R) -> Self {
         Self {
             adjectives: adjectives(),
             nouns: nouns(),
             naming: Name::Plain,
             casing: Casing::Lowercase(NumberSeperator::Dash),
             length: Length::None,
-            rng: OsRng,
+            max_reroll_attempts: max_reroll_attempts(),
+            title_case_stop_words: title_case_stop_words(),
+            number_position: NumberPosition::Suffix,
+            adjective_count: adjective_count(),
+            distinct_words: false,
+            adjective_weights: None,
+            noun_weights: None,
+            alliterative: false,
+            min_word_len: None,
+            max_word_len: None,
+            word_separator: None,
+            dedup_dictionaries: false,
+            max_syllables: None,
+            reject_separator_in_words: false,
+            ascii_only: false,
+            sequence: 0,
+            attempts: 0,
+            structure: Structure::AdjectiveNoun,
+            blocklist: BTreeSet::new(),
+            preserve_all_caps_words: false,
+            min_combinations: None,
+            normalize_case: false,
+            transform: None,
+            rng,
         }
     }
-}
-impl Default for Generator<StdRng> {
-    fn default() -> Self {
-        use rand::SeedableRng;
 
-        Self {
-            adjectives: adjectives(),
-            nouns: nouns(),
-            naming: Name::Plain,
-            casing: Casing::Lowercase(NumberSeperator::Dash),
-            length: Length::None,
-            rng: StdRng::from_entropy(),
+    /// Returns the total number of distinct names this generator can produce,
+    /// saturating at `u128::MAX`
+    pub fn combinations(&self) -> u128 {
+        if self.structure == Structure::DoubleNoun {
+            let nouns = self.nouns.len() as u128;
+            return (nouns * nouns.saturating_sub(1)).saturating_mul(self.naming.combinations());
         }
+
+        let adjectives = self.adjectives.len() as u128;
+        let count = self.adjective_slots();
+        let adjective_combinations = if count as u128 <= adjectives {
+            (0..count as u128).fold(1u128, |acc, i| acc.saturating_mul(adjectives - i))
+        } else {
+            pow_saturating(adjectives, count)
+        };
+
+        adjective_combinations
+            .saturating_mul(self.noun_slots() as u128)
+            .saturating_mul(self.naming.combinations())
     }
-}
-impl Default for Generator<SmallRng> {
-    fn default() -> Self {
-        use rand::SeedableRng;
 
-        Self {
-            adjectives: adjectives(),
-            nouns: nouns(),
-            naming: Name::Plain,
-            casing: Casing::Lowercase(NumberSeperator::Dash),
-            length: Length::None,
-            rng: SmallRng::from_entropy(),
+    /// Returns the shortest and longest character lengths of the names this
+    /// generator can produce, before the [`Length`] option is applied
+    ///
+    /// The bounds assume the [`Casing`] preserves the length of each word, and
+    /// ignore `distinct_words`, `alliterative` and `max_syllables`, so they may
+    /// be wider than the lengths actually produced.
+    pub fn length_bounds(&self) -> (usize, usize) {
+        let transform = self.transform.as_ref();
+        let lengths = |words: &[Cow<'static, str>]| {
+            let mut lengths = words.iter().map(|word| text_len(&transform_word(transform, word))).collect::<Vec<_>>();
+            lengths.sort_unstable();
+            lengths
+        };
+        let adjectives = lengths(self.leading_words());
+        let nouns = lengths(&self.nouns);
+
+        let count = self.adjective_slots();
+        let weighted = match self.structure {
+            Structure::DoubleNoun => self.noun_weights.is_some(),
+            _ => self.adjective_weights.is_some(),
+        };
+        let (adjectives_min, adjectives_max) = if !weighted && count <= adjectives.len() {
+            (adjectives[..count].iter().sum(), adjectives[adjectives.len() - count..].iter().sum())
+        } else {
+            (count * adjectives[0], count * adjectives[adjectives.len() - 1])
+        };
+        let (nouns_min, nouns_max, words) = match self.structure {
+            Structure::AdjectiveOnly => (0, 0, count),
+            _ => (nouns[0], nouns[nouns.len() - 1], count + 1),
+        };
+        let seperators = words.saturating_sub(1) * text_len(&self.word_seperator());
+        let (naming_min, naming_max) = self.naming.width();
+        let seperators = seperators + text_len(self.token_seperator());
+
+        (
+            adjectives_min + nouns_min + seperators + naming_min,
+            adjectives_max + nouns_max + seperators + naming_max,
+        )
+    }
+
+    #[cfg(feature = "std")]
+    /// Estimates the probability that `k` generated names contain at least one
+    /// collision, using the birthday approximation `1 - exp(-k(k - 1) / 2N)`
+    /// where `N` is [`combinations`](Self::combinations)
+    ///
+    /// Returns `0.0` for fewer than two names, and `1.0` when no names can be
+    /// produced.
+    pub fn collision_probability(&self, k: u64) -> f64 {
+        let combinations = self.combinations();
+        if k <= 1 {
+            return 0.0;
+        }
+        if combinations == 0 {
+            return 1.0;
         }
+
+        let exponent = k as f64 * (k - 1) as f64 / (2.0 * combinations as f64);
+        (-(-exponent).exp_m1()).clamp(0.0, 1.0)
     }
-}
 
-impl<R: Rng> Iterator for Generator<R> {
-    type Item = String;
+    /// Returns the adjectives, after any build-time filtering
+    pub fn adjectives(&self) -> &[Cow<'static, str>] {
+        &self.adjectives
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let adj = self.adjectives.choose(&mut self.rng)?;
-        let noun = self.nouns.choose(&mut self.rng)?;
-        let combined = self.casing.apply(vec![adj, noun]);
-
-        let mut generated = match &self.naming {
-            Name::Plain => combined,
-            Name::Numbered(x, num_sep) => format!("{combined}{num_sep}{}", generate_number_with_x_digits(*x, &mut self.rng)),
-            Name::ZeroPaddedNumbered(x, num_sep) => format!("{combined}{num_sep}{}", generate_padded_number_with_x_digits(*x, &mut self.rng)),
+    /// Returns the nouns, after any build-time filtering
+    pub fn nouns(&self) -> &[Cow<'static, str>] {
+        &self.nouns
+    }
+
+    /// Returns an iterator over the adjectives, after any build-time filtering
+    pub fn adjectives_iter(&self) -> impl Iterator<Item = &str> {
+        self.adjectives.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns an iterator over the nouns, after any build-time filtering
+    pub fn nouns_iter(&self) -> impl Iterator<Item = &str> {
+        self.nouns.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns whether the word is one of the adjectives, ignoring case
+    pub fn is_adjective(&self, word: &str) -> bool {
+        contains_ignoring_case(&self.adjectives, word)
+    }
+
+    /// Returns whether the word is one of the nouns, ignoring case
+    pub fn is_noun(&self, word: &str) -> bool {
+        contains_ignoring_case(&self.nouns, word)
+    }
+
+    /// Returns the RNG, so that extra values can be drawn from the same
+    /// stream as the names
+    ///
+    /// Every value drawn advances the RNG, so interleaving draws with names
+    /// changes the names that follow, though reproducibly for a seeded RNG.
+    pub fn rng_mut(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Replaces the RNG, keeping the rest of the configuration, so that a
+    /// built generator can be rerun from a different RNG state
+    pub fn set_rng(&mut self, rng: R) {
+        self.rng = rng;
+    }
+
+    /// Returns the configuration of the generator without its RNG, after any
+    /// build-time filtering, so that it can be rebuilt with another RNG using
+    /// [`GeneratorConfig::with_rng`]
+    pub fn config(&self) -> GeneratorConfig {
+        GeneratorConfig {
+            adjectives: self.adjectives.clone(),
+            nouns: self.nouns.clone(),
+            naming: self.naming.clone(),
+            casing: self.casing.clone(),
+            length: self.length,
+            max_reroll_attempts: self.max_reroll_attempts,
+            title_case_stop_words: self.title_case_stop_words.clone(),
+            number_position: self.number_position,
+            adjective_count: self.adjective_count,
+            distinct_words: self.distinct_words,
+            adjective_weights: self.adjective_weights.clone(),
+            noun_weights: self.noun_weights.clone(),
+            alliterative: self.alliterative,
+            min_word_len: self.min_word_len,
+            max_word_len: self.max_word_len,
+            word_separator: self.word_separator.clone(),
+            dedup_dictionaries: self.dedup_dictionaries,
+            max_syllables: self.max_syllables,
+            reject_separator_in_words: self.reject_separator_in_words,
+            ascii_only: self.ascii_only,
+            structure: self.structure,
+            blocklist: self.blocklist.clone(),
+            preserve_all_caps_words: self.preserve_all_caps_words,
+            min_combinations: self.min_combinations,
+            normalize_case: self.normalize_case,
+        }
+    }
+
+    /// Returns a name which is a valid RFC 1123 DNS label, or `None` if the
+    /// generator gives up
+    ///
+    /// The name is lowercased, stripped of any characters other than ASCII
+    /// letters, digits and `-`, truncated to 63 characters and trimmed of
+    /// leading and trailing `-`. Names left empty are rerolled up to
+    /// `max_reroll_attempts` times.
+    pub fn dns_label(&mut self) -> Option<String> {
+        for _ in 0..self.max_reroll_attempts.max(1) {
+            let label = to_dns_label(&self.next()?);
+            if !label.is_empty() {
+                return Some(label);
+            }
+        }
+        None
+    }
+
+    /// Returns the next name, or why none could be generated
+    ///
+    /// Fails with [`Error::RerollLimitExceeded`] when `max_reroll_attempts`
+    /// names in a row are rejected, such as by the [`Length`], the blocklist
+    /// or `distinct_words`, and with [`Error::EmptyIterator`] when no words
+    /// can be drawn at all, such as when every remaining word has a weight of
+    /// zero. [`Iterator::next`] returns `None` in both cases.
+    pub fn try_next(&mut self) -> Result<String, Error> {
+        let mut name = String::new();
+        self.try_next_into(&mut name)?;
+        Ok(name)
+    }
+
+    /// Writes the next name into the buffer, clearing it first, and returns
+    /// whether there was one
+    ///
+    /// The name is the same as [`next`](Iterator::next) would return, but
+    /// reusing the buffer's allocation saves allocating a `String` per name in
+    /// a loop. The buffer is left empty when the generator gives up.
+    pub fn next_into(&mut self, buf: &mut String) -> bool {
+        let generated = self.try_next_into(buf).is_ok();
+        if !generated {
+            buf.clear();
+        }
+        generated
+    }
+
+    /// Returns the next name accepted by the predicate, or `None` if the
+    /// generator gives up
+    ///
+    /// Names are rerolled until `pred` returns `true`, in the same loop as the
+    /// [`Length`] and blocklist, so together they are bounded by
+    /// `max_reroll_attempts`.
+    pub fn next_matching(&mut self, pred: impl Fn(&str) -> bool) -> Option<String> {
+        let mut name = String::new();
+        self.try_next_matching_into(&mut name, pred).ok().map(|_| name)
+    }
+
+    /// Returns the next name using the given naming strategy instead of the
+    /// configured one, or `None` if the generator gives up or the naming
+    /// strategy is invalid
+    ///
+    /// The configured naming strategy is left unchanged, and the [`Casing`],
+    /// [`Length`] and other options apply as usual.
+    pub fn next_with(&mut self, naming: &Name) -> Option<String> {
+        self.try_next_with(naming).ok()
+    }
+
+    /// Returns the next name using the given naming strategy instead of the
+    /// configured one, or why none could be generated
+    ///
+    /// Fails with the same error as [`GeneratorBuilder::build`] when the
+    /// naming strategy is invalid, such as [`Error::ZeroDigits`], and
+    /// otherwise as [`try_next`](Self::try_next) does.
+    pub fn try_next_with(&mut self, naming: &Name) -> Result<String, Error> {
+        naming.validate()?;
+        let configured = core::mem::replace(&mut self.naming, naming.clone());
+        let name = self.try_next();
+        self.naming = configured;
+        name
+    }
+
+    /// Returns the next name along with the words and number it is made of,
+    /// or `None` if the generator gives up
+    ///
+    /// The name is the same as the one [`next`](Iterator::next) would return,
+    /// drawing the same values from the RNG.
+    pub fn next_parts(&mut self) -> Option<NameParts> {
+        let mut formatted = String::new();
+        let (adjectives, noun, number) = self.try_next_matching_into(&mut formatted, |_| true).ok()?;
+        let leading = self.leading_words();
+        let adjective = adjectives.iter().map(|&i| leading[i].as_ref()).collect::<Vec<_>>().join(" ");
+        let noun = match self.structure {
+            Structure::AdjectiveOnly => String::new(),
+            _ => self.nouns[noun].to_string(),
         };
-        
-        Some(match self.length {
-            Length::Truncate(x) => { generated.truncate(x); generated },
-            Length::Reroll(x) => {
-                while generated.len() != x {
-                    generated = self.next()?;
+        Some(NameParts { adjective, noun, number, formatted })
+    }
+
+    fn try_next_into(&mut self, out: &mut String) -> Result<(), Error> {
+        self.try_next_matching_into(out, |_| true).map(|_| ())
+    }
+
+    /// Writes the next name accepted by the predicate to the buffer, returning
+    /// the chosen words and number
+    fn try_next_matching_into(&mut self, out: &mut String, pred: impl Fn(&str) -> bool) -> Result<(Vec<usize>, usize, Option<u64>), Error> {
+        self.attempts = 0;
+        for _ in 0..self.max_reroll_attempts.max(1) {
+            out.clear();
+            let chosen = self.generate_into(out)?;
+            if self.fit_in_place(out) && !self.blocked(out) && pred(out) {
+                self.sequence = self.sequence.wrapping_add(1);
+                return Ok(chosen);
+            }
+        }
+        Err(Error::RerollLimitExceeded)
+    }
+
+    /// Returns the number of names drawn by the last call to
+    /// [`next`](Iterator::next) or [`Generator::try_next`], including the one
+    /// returned
+    ///
+    /// Each choice of words rejected by `distinct_words`, `alliterative` or
+    /// `max_syllables`, and each name rejected by the [`Length`] or blocklist,
+    /// counts as an attempt, so a count well above 1 points to a configuration
+    /// that rerolls often. It is 0 before the first name.
+    pub fn last_attempt_count(&self) -> usize {
+        self.attempts
+    }
+
+    /// Returns a name built from a template such as `"{adj}_{noun}_{num:3}"`
+    ///
+    /// Each `{adj}` and `{noun}` is replaced with a randomly chosen adjective
+    /// or noun, honouring the weights and transform but not the [`Casing`],
+    /// and each `{num:N}` with a random number of `N` digits, where `N` is
+    /// between 1 and [`MAX_DIGITS`]. Other text is copied as is, with `{{` and
+    /// `}}` standing for literal braces. The [`Name`], [`Length`] and other
+    /// filters do not apply. Fails with [`Error::InvalidTemplate`] on an
+    /// unknown or unclosed placeholder.
+    pub fn from_template(&mut self, template: &str) -> Result<String, Error> {
+        let mut name = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(index) = rest.find(['{', '}']) {
+            name.push_str(&rest[..index]);
+            let brace = &rest[index..index + 1];
+            rest = &rest[index + 1..];
+            if let Some(after) = rest.strip_prefix(brace) {
+                name.push_str(brace);
+                rest = after;
+                continue;
+            }
+            if brace == "}" {
+                return Err(Error::InvalidTemplate(format!("unmatched `}}` in {:?}", template)));
+            }
+
+            let (token, after) = rest.split_once('}').ok_or_else(|| Error::InvalidTemplate(format!("unclosed `{{` in {:?}", template)))?;
+            rest = after;
+            match token.split_once(':') {
+                None if token == "adj" => {
+                    let i = choose_index(&mut self.rng, self.adjectives.len(), self.adjective_weights.as_deref()).ok_or(Error::EmptyIterator)?;
+                    name.push_str(&transform_word(self.transform.as_ref(), &self.adjectives[i]));
+                },
+                None if token == "noun" => {
+                    let i = choose_index(&mut self.rng, self.nouns.len(), self.noun_weights.as_deref()).ok_or(Error::EmptyIterator)?;
+                    name.push_str(&transform_word(self.transform.as_ref(), &self.nouns[i]));
+                },
+                Some(("num", digits)) => match digits.parse::<usize>() {
+                    Ok(x @ 1..=MAX_DIGITS) => name.push_str(&generate_number_with_x_digits(x, &mut self.rng).to_string()),
+                    _ => return Err(Error::InvalidTemplate(format!("`{{{}}}` needs between 1 and {} digits", token, MAX_DIGITS))),
+                },
+                _ => return Err(Error::InvalidTemplate(format!("unknown placeholder `{{{}}}`", token))),
+            }
+        }
+        name.push_str(rest);
+        Ok(name)
+    }
+
+    /// Returns an iterator borrowing the generator, so that it can be looped
+    /// over and kept afterward
+    ///
+    /// This is equivalent to `&mut generator` or [`Iterator::by_ref`], which
+    /// work because `&mut I` is an iterator for any iterator `I`.
+    pub fn iter_mut(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Returns up to `n` names, fewer if the generator gives up early
+    pub fn sample(&mut self, n: usize) -> Vec<String> {
+        self.take(n).collect()
+    }
+
+    /// Returns up to `n` names joined by the delimiter, fewer if the generator
+    /// gives up early
+    pub fn join(&mut self, n: usize, delimiter: &str) -> String {
+        self.sample(n).join(delimiter)
+    }
+
+    #[cfg(feature = "stream")]
+    /// Converts the generator into a [`NameStream`], which yields names
+    /// asynchronously
+    pub fn into_stream(self) -> NameStream<R> {
+        NameStream { generator: self }
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns `k` distinct names
+    ///
+    /// Fails with [`Error::Exhausted`] if the generator has fewer than `k`
+    /// [`combinations`](Self::combinations), or draws `max_reroll_attempts`
+    /// duplicates in a row, and with the error of [`try_next`](Self::try_next)
+    /// if it gives up on a name.
+    pub fn draw_unique(&mut self, k: usize) -> Result<Vec<String>, Error> {
+        if k as u128 > self.combinations() {
+            return Err(Error::Exhausted);
+        }
+
+        let mut emitted = HashSet::with_capacity(k);
+        let mut names = Vec::with_capacity(k);
+        let mut duplicates = 0;
+        while names.len() < k {
+            let name = self.try_next()?;
+            if emitted.insert(name.clone()) {
+                names.push(name);
+                duplicates = 0;
+            } else {
+                duplicates += 1;
+                if duplicates >= self.max_reroll_attempts.max(1) {
+                    return Err(Error::Exhausted);
                 }
-                generated
-            },
-            Length::None => generated,
-        })
+            }
+        }
+        Ok(names)
     }
-}
 
-fn generate_number_with_x_digits<R: Rng + ?Sized>(x: usize, rng: &mut R) -> usize {
-    let lower_bound = 10usize.pow((x - 1) as u32);
-    let upper_bound = 10usize.pow(x as u32) - 1;
-    rng.gen_range(lower_bound..=upper_bound)
-}
+    #[cfg(feature = "std")]
+    /// Wraps the generator so that it never yields the same name twice,
+    /// returning `None` once every combination has been emitted
+    pub fn unique(self) -> UniqueGenerator<R> {
+        UniqueGenerator {
+            generator: self,
+            emitted: HashSet::new(),
+        }
+    }
+
+    /// Consumes the generator, returning an iterator which yields every name in
+    /// a random order, each exactly once
+    ///
+    /// Every choice of adjectives and noun is materialized up front and
+    /// shuffled, so memory grows with the size of that space and word weights
+    /// are ignored. Numbers are enumerated as well when the whole space holds
+    /// at most 2<sup>20</sup> names; beyond that, each pair of words is only
+    /// yielded once and a fresh random number is appended to it, so fewer than
+    /// [`combinations`](Self::combinations) names are produced. A [`Length`]
+    /// other than [`Length::None`] skips or truncates names as usual, which
+    /// can also shrink the output or, when truncating, repeat names.
+    pub fn into_exhaustive(mut self) -> ExhaustiveGenerator<R> {
+        let (words, order) = self.shuffled_space();
+        ExhaustiveGenerator {
+            generator: self,
+            words,
+            order,
+        }
+    }
+
+    /// Consumes the generator, returning an iterator which shuffles every name
+    /// once and then repeats that order forever
+    ///
+    /// Each cycle holds the names [`Generator::into_exhaustive`] would yield,
+    /// so when numbers are enumerated and no name is skipped, any
+    /// [`combinations`](Self::combinations) consecutive names are all
+    /// different. Beyond 2<sup>20</sup> names a fresh random number is
+    /// drawn for each pair of words on every cycle. The iterator only ends when
+    /// the [`Length`] or blocklist rejects a whole cycle.
+    pub fn into_cycle(mut self) -> CycleGenerator<R> {
+        let (words, order) = self.shuffled_space();
+        CycleGenerator {
+            generator: self,
+            words,
+            order,
+            position: 0,
+        }
+    }
+
+    /// Returns every choice of words, along with the shuffled order of the
+    /// names made from them, see [`Generator::into_exhaustive`]
+    #[allow(clippy::type_complexity)]
+    fn shuffled_space(&mut self) -> (Vec<(Vec<usize>, usize)>, Vec<(usize, Option<u128>)>) {
+        use rand::seq::SliceRandom;
+
+        let words = self.word_space();
+        let numbers = self.naming.combinations();
+        let enumerate = numbers > 1 && (words.len() as u128).saturating_mul(numbers) <= EXHAUSTIVE_LIMIT;
+
+        let mut order = (0..words.len())
+            .flat_map(|word| {
+                let numbers = if enumerate { numbers } else { 1 };
+                (0..numbers).map(move |number| (word, enumerate.then_some(number)))
+            })
+            .collect::<Vec<_>>();
+        order.shuffle(&mut self.rng);
+        (words, order)
+    }
+
+    /// Removes the words excluded by the build-time options, along with their
+    /// weights, and checks the remaining words
+    fn filter_words(&mut self) -> Result<(), Error> {
+        if self.normalize_case {
+            lowercase_words(&mut self.adjectives);
+            lowercase_words(&mut self.nouns);
+        }
+        if self.dedup_dictionaries {
+            dedup_words(&mut self.adjectives, &mut self.adjective_weights);
+        }
+        if self.dedup_dictionaries || self.structure == Structure::DoubleNoun {
+            dedup_words(&mut self.nouns, &mut self.noun_weights);
+        }
+
+        let (min, max) = (self.min_word_len.unwrap_or(0), self.max_word_len.unwrap_or(usize::MAX));
+        let ascii_only = self.ascii_only;
+        let blocklist = &self.blocklist;
+        let keep = |word: &str| {
+            (min..=max).contains(&word.chars().count())
+                && (!ascii_only || word.is_ascii())
+                && !blocklist.contains(&word.to_lowercase())
+        };
+
+        retain_words(&mut self.adjectives, &mut self.adjective_weights, keep);
+        if self.adjectives.is_empty() {
+            return Err(Error::AdjectivesEmpty);
+        }
+        retain_words(&mut self.nouns, &mut self.noun_weights, keep);
+        if self.nouns.is_empty() {
+            return Err(Error::NounsEmpty);
+        }
+        if self.structure == Structure::DoubleNoun && self.nouns.len() < 2 {
+            return Err(Error::TooFewNouns);
+        }
 
-fn generate_padded_number_with_x_digits<R: Rng + ?Sized>(x: usize, rng: &mut R) -> String {
-    let number = generate_number_with_x_digits(x, rng);
-    format!("{:0>width$}", number, width = x)
-}
\ No newline at end of file
+        let seperator = self.word_seperator();
+        if self.reject_separator_in_words && !seperator.is_empty() {
+            if let Some(word) = self.adjectives.iter().chain(&self.nouns).find(|word| word.contains(seperator.as_str())) {
+                return Err(Error::SeparatorInWord(word.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the seperator joining the words
+    fn word_seperator(&self) -> String {
+        match &self.word_separator {
+            Some(seperator) => self.casing.with_seperator(seperator).seperator(),
+            None => self.casing.seperator(),
+        }
+    }
+
+    /// Returns the seperator attaching the number or affix of the naming
+    /// strategy. A number's seperator is dropped for the casings joining the
+    /// words without one, while an affix always keeps its seperator.
+    fn token_seperator(&self) -> &str {
+        let joined = matches!(self.casing, Casing::CamelCase | Casing::PascalCase | Casing::FlatCase);
+        match self.naming.seperator() {
+            Some(_) if self.naming.is_numeric() && joined => "",
+            Some(seperator) => seperator.as_str(),
+            None => "",
+        }
+    }
+
+    fn case(&self, words: Vec<&str>) -> String {
+        let mut out = String::new();
+        self.case_into(&mut out, words);
+        out
+    }
+
+    fn case_into(&self, out: &mut String, words: Vec<&str>) {
+        match &self.word_separator {
+            Some(separator) => self.casing.with_seperator(separator).apply_into(out, words, &self.title_case_stop_words, self.preserve_all_caps_words),
+            None => self.casing.apply_into(out, words, &self.title_case_stop_words, self.preserve_all_caps_words),
+        }
+    }
+
+    /// Chooses the indices of the adjectives and the noun of a name, honouring
+    /// `distinct_words`, `alliterative` and `max_syllables`
+    fn choose_words(&mut self) -> Result<(Vec<usize>, usize), Error> {
+        let filtered = self.distinct_words || self.alliterative || self.max_syllables.is_some() || self.structure == Structure::DoubleNoun;
+        let count = self.adjective_slots();
+
+        for _ in 0..self.max_reroll_attempts.max(1) {
+            self.attempts += 1;
+            let (len, weights) = match self.structure {
+                Structure::DoubleNoun => (self.nouns.len(), self.noun_weights.as_deref()),
+                _ => (self.adjectives.len(), self.adjective_weights.as_deref()),
+            };
+            let adjectives = choose_indices(&mut self.rng, len, count, weights).ok_or(Error::EmptyIterator)?;
+            if !filtered {
+                let noun = choose_index(&mut self.rng, self.nouns.len(), self.noun_weights.as_deref()).ok_or(Error::EmptyIterator)?;
+                return Ok((adjectives, noun));
+            }
+
+            let candidates = (0..self.noun_slots()).filter(|&i| self.allows(&adjectives, i)).collect::<Vec<_>>();
+            if candidates.is_empty() {
+                continue;
+            }
+            let weights = self.noun_weights.as_ref().map(|weights| candidates.iter().map(|&i| weights[i]).collect::<Vec<_>>());
+            if let Some(i) = choose_index(&mut self.rng, candidates.len(), weights.as_deref()) {
+                return Ok((adjectives, candidates[i]));
+            }
+        }
+        Err(Error::RerollLimitExceeded)
+    }
+
+    fn generate_into(&mut self, out: &mut String) -> Result<(Vec<usize>, usize, Option<u64>), Error> {
+        let (adjectives, noun) = self.choose_words()?;
+        let number = self.render_into(out, &adjectives, noun, None);
+        Ok((adjectives, noun, number))
+    }
+
+    /// Renders a name from the chosen words, using the `number`th value of the
+    /// naming scheme or a random one when `None`
+    fn render(&mut self, adjectives: &[usize], noun: usize, number: Option<u128>) -> String {
+        let mut name = String::new();
+        self.render_into(&mut name, adjectives, noun, number);
+        name
+    }
+
+    /// Appends the rendered name to the buffer, see [`Generator::render`],
+    /// returning the decimal number of the naming scheme, if any
+    fn render_into(&mut self, out: &mut String, adjectives: &[usize], noun: usize, number: Option<u128>) -> Option<u64> {
+        let position = match self.naming {
+            Name::Plain => None,
+            Name::Prefixed(..) => Some(NumberPosition::Prefix),
+            Name::Suffixed(..) | Name::HexSuffixed(..) | Name::EncodedSuffix { .. } => Some(NumberPosition::Suffix),
+            _ => Some(self.number_position),
+        };
+        match position {
+            None => {
+                self.case_words_into(out, adjectives, noun);
+                None
+            },
+            Some(NumberPosition::Prefix) => {
+                let number = self.push_token(out, number);
+                out.push_str(self.token_seperator());
+                self.case_words_into(out, adjectives, noun);
+                number
+            },
+            Some(NumberPosition::Suffix) => {
+                self.case_words_into(out, adjectives, noun);
+                out.push_str(self.token_seperator());
+                self.push_token(out, number)
+            },
+        }
+    }
+
+    /// Appends the cased words, after any transform, to the buffer
+    fn case_words_into(&self, out: &mut String, adjectives: &[usize], noun: usize) {
+        let words = self.words(adjectives, noun);
+        match self.transform.as_ref() {
+            Some(transform) => {
+                let words = words.into_iter().map(|word| transform_word(Some(transform), word)).collect::<Vec<_>>();
+                self.case_into(out, words.iter().map(|word| word.as_ref()).collect())
+            },
+            None => self.case_into(out, words),
+        }
+    }
+
+    /// Appends the number or affix of the naming scheme to the buffer, using
+    /// the `number`th value or a random one when `None`, and returns the
+    /// decimal number written, if any
+    fn push_token(&mut self, out: &mut String, number: Option<u128>) -> Option<u64> {
+        use core::fmt::Write;
+
+        let number = match &self.naming {
+            Name::Plain => return None,
+            Name::Numbered(x, _) => match number {
+                Some(i) => 10u128.pow((*x - 1) as u32) + i,
+                None => u128::from(generate_number_with_x_digits(*x, &mut self.rng)),
+            },
+            Name::ZeroPaddedNumbered(x, _) => {
+                let number = match number {
+                    Some(i) => i,
+                    None => u128::from(self.rng.gen_range(0..10u64.pow(*x as u32))),
+                };
+                // writing to a `String` never fails
+                let _ = write!(out, "{:0>width$}", number, width = x);
+                return u64::try_from(number).ok();
+            },
+            Name::NumberedRange(start, end, _) => match number {
+                Some(i) => u128::from(*start) + i,
+                None => u128::from(self.rng.gen_range(*start..=*end)),
+            },
+            Name::Prefixed(affix, _) | Name::Suffixed(affix, _) => {
+                self.case_into(out, vec![affix]);
+                return None;
+            },
+            Name::HexSuffixed(x, _) => {
+                match number {
+                    Some(i) => {
+                        let _ = write!(out, "{:0>width$x}", i, width = x);
+                    },
+                    None => out.push_str(&generate_hex_with_x_digits(*x, &mut self.rng)),
+                }
+                return None;
+            },
+            Name::EncodedSuffix { chars, alphabet, .. } => {
+                let symbols = alphabet.chars().collect::<Vec<_>>();
+                match number {
+                    Some(i) => out.push_str(&encode_with_x_symbols(i, *chars, &symbols)),
+                    None => out.push_str(&generate_encoded_with_x_symbols(*chars, &symbols, &mut self.rng)),
+                }
+                return None;
+            },
+            Name::Timestamped(_) => u128::from(unix_timestamp()),
+            Name::Sequential(_) => u128::from(self.sequence.wrapping_add(1)),
+            Name::VariableNumbered { min_digits, max_digits, .. } => {
+                // the numbers of every digit count form one contiguous range
+                match number {
+                    Some(i) => 10u128.pow((*min_digits - 1) as u32) + i,
+                    None => {
+                        let x = self.rng.gen_range(*min_digits..=*max_digits);
+                        u128::from(generate_number_with_x_digits(x, &mut self.rng))
+                    },
+                }
+            },
+        };
+        let _ = write!(out, "{}", number);
+        u64::try_from(number).ok()
+    }
+
+    /// Returns whether the name, or any of its alphanumeric runs, is in the
+    /// blocklist
+    fn blocked(&self, name: &str) -> bool {
+        if self.blocklist.is_empty() {
+            return false;
+        }
+
+        let name = name.to_lowercase();
+        self.blocklist.contains(&name)
+            || name
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|token| self.blocklist.contains(token))
+    }
+
+    /// Applies the [`Length`] option, returning `None` if the name must be
+    /// rerolled
+    fn fit_length(&self, mut name: String) -> Option<String> {
+        self.fit_in_place(&mut name).then_some(name)
+    }
+
+    /// Applies the [`Length`] option to the buffer, returning `false` if the
+    /// name must be rerolled
+    fn fit_in_place(&self, name: &mut String) -> bool {
+        match self.length {
+            Length::Truncate(x) => {
+                truncate_chars(name, x);
+                true
+            },
+            Length::TruncateBytes(x) => {
+                truncate_bytes(name, x);
+                true
+            },
+            Length::Reroll(x) => text_len(name) == x,
+            Length::Range(min, max) => (min..=max).contains(&text_len(name)),
+            Length::None => true,
+        }
+    }
+
+    /// Returns whether the words fit within `max_syllables`
+    fn within_syllables(&self, adjectives: &[usize], noun: usize) -> bool {
+        match self.max_syllables {
+            Some(max) => self.words(adjectives, noun).into_iter().map(count_syllables).sum::<usize>() <= max,
+            None => true,
+        }
+    }
+
+    /// Returns every choice of adjectives and noun, honouring `distinct_words`,
+    /// `alliterative` and `max_syllables`
+    fn word_space(&self) -> Vec<(Vec<usize>, usize)> {
+        let len = self.leading_words().len();
+        let distinct = self.adjective_slots() <= len;
+        let mut tuples: Vec<Vec<usize>> = vec![Vec::new()];
+        for _ in 0..self.adjective_slots() {
+            tuples = tuples
+                .iter()
+                .flat_map(|tuple| {
+                    (0..len)
+                        .filter(|i| !distinct || !tuple.contains(i))
+                        .map(|i| tuple.iter().copied().chain(Some(i)).collect())
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+        }
+
+        let mut space = Vec::new();
+        for adjectives in tuples {
+            for noun in 0..self.noun_slots() {
+                if self.allows(&adjectives, noun) {
+                    space.push((adjectives.clone(), noun));
+                }
+            }
+        }
+        space
+    }
+
+    /// Returns whether the words satisfy `distinct_words`, `alliterative` and
+    /// `max_syllables`, and for [`Structure::DoubleNoun`] differ
+    fn allows(&self, adjectives: &[usize], noun: usize) -> bool {
+        match self.structure {
+            Structure::AdjectiveNoun => {},
+            Structure::DoubleNoun => {
+                return adjectives.iter().all(|&i| i != noun) && self.within_syllables(adjectives, noun);
+            },
+            _ => return self.within_syllables(adjectives, noun),
+        }
+
+        let letter = adjectives.first().and_then(|&i| initial(&self.adjectives[i]));
+        (!self.alliterative || initial(&self.nouns[noun]) == letter)
+            && (!self.distinct_words || adjectives.iter().all(|&j| self.adjectives[j] != self.nouns[noun]))
+            && self.within_syllables(adjectives, noun)
+    }
+
+    /// Returns the words making up a name, according to the [`Structure`]
+    fn words(&self, adjectives: &[usize], noun: usize) -> Vec<&str> {
+        let leading = self.leading_words();
+        let mut words = adjectives.iter().map(|&i| leading[i].as_ref()).collect::<Vec<_>>();
+        if self.structure != Structure::AdjectiveOnly {
+            words.push(&self.nouns[noun]);
+        }
+        words
+    }
+
+    /// Fails with [`Error::UnreachableLength`] when a [`Length::Reroll`] lies
+    /// outside of [`Generator::length_bounds`], skipped for a [`Casing::Custom`]
+    /// which may change the length of the words
+    fn check_length(&self) -> Result<(), Error> {
+        let x = match self.length {
+            Length::Reroll(x) => x,
+            _ => return Ok(()),
+        };
+        if let Casing::Custom { .. } = self.casing {
+            return Ok(());
+        }
+        let (shortest, longest) = self.length_bounds();
+        if x < shortest || x > longest {
+            return Err(Error::UnreachableLength(self.length, shortest, longest));
+        }
+        Ok(())
+    }
+
+    /// Returns the words chosen before the noun, which are the nouns for
+    /// [`Structure::DoubleNoun`] and otherwise the adjectives
+    fn leading_words(&self) -> &[Cow<'static, str>] {
+        match self.structure {
+            Structure::DoubleNoun => &self.nouns,
+            _ => &self.adjectives,
+        }
+    }
+
+    /// Returns the number of adjectives, or leading nouns, in a name
+    fn adjective_slots(&self) -> usize {
+        match self.structure {
+            Structure::NounOnly => 0,
+            Structure::DoubleNoun => 1,
+            _ => self.adjective_count,
+        }
+    }
+
+    /// Returns the number of nouns to choose from, only the first when they are
+    /// left out of the name
+    fn noun_slots(&self) -> usize {
+        match self.structure {
+            Structure::AdjectiveOnly => 1,
+            _ => self.nouns.len(),
+        }
+    }
+
+    /// Returns whether the candidate could have been generated, ignoring any
+    /// [`Length`] and transform
+    ///
+    /// The number or affix of the naming strategy is split off at its
+    /// seperator, then the rest is matched word by word against the adjectives
+    /// and noun and must be cased exactly as the [`Casing`] would. Every way of
+    /// splitting the candidate is tried, so words or affixes containing the
+    /// seperator are still matched, but a name may be accepted for a different
+    /// choice of words than the one that generated it.
+    pub fn matches(&self, candidate: &str) -> bool {
+        let digits = |token: &str, x: usize| token.len() == x && token.bytes().all(|b| b.is_ascii_digit());
+        let position = self.number_position;
+        let seperator = self.token_seperator();
+
+        match &self.naming {
+            Name::Plain => self.matches_words(candidate),
+            Name::Numbered(x, _) => self.matches_attached(candidate, seperator, position, |token| digits(token, *x) && !token.starts_with('0')),
+            Name::ZeroPaddedNumbered(x, _) => self.matches_attached(candidate, seperator, position, |token| digits(token, *x)),
+            Name::NumberedRange(start, end, _) => self.matches_attached(candidate, seperator, position, |token| {
+                token.parse::<u64>().is_ok_and(|number| (start..=end).contains(&&number) && number.to_string() == token)
+            }),
+            Name::Prefixed(prefix, _) => self.matches_attached(candidate, seperator, NumberPosition::Prefix, |token| token == self.case(vec![prefix])),
+            Name::Suffixed(suffix, _) => self.matches_attached(candidate, seperator, NumberPosition::Suffix, |token| token == self.case(vec![suffix])),
+            Name::HexSuffixed(x, _) => self.matches_attached(candidate, seperator, NumberPosition::Suffix, |token| {
+                token.len() == *x && token.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+            }),
+            Name::Timestamped(_) => self.matches_attached(candidate, seperator, position, |token| {
+                token.parse::<u64>().is_ok_and(|seconds| seconds.to_string() == token)
+            }),
+            Name::Sequential(_) => self.matches_attached(candidate, seperator, position, |token| {
+                token.parse::<u64>().is_ok_and(|number| number > 0 && number.to_string() == token)
+            }),
+            Name::EncodedSuffix { chars, alphabet, .. } => self.matches_attached(candidate, seperator, NumberPosition::Suffix, |token| {
+                token.chars().count() == *chars && token.chars().all(|c| alphabet.contains(c))
+            }),
+            Name::VariableNumbered { min_digits, max_digits, .. } => self.matches_attached(candidate, seperator, position, |token| {
+                (*min_digits..=*max_digits).any(|x| digits(token, x)) && !token.starts_with('0')
+            }),
+        }
+    }
+
+    /// Returns whether the candidate is a token accepted by `is_token` attached
+    /// at the position to a name matching the words
+    fn matches_attached(&self, candidate: &str, seperator: &str, position: NumberPosition, is_token: impl Fn(&str) -> bool) -> bool {
+        (0..=candidate.len())
+            .filter(|&i| candidate.is_char_boundary(i))
+            .any(|i| {
+                let (start, end) = candidate.split_at(i);
+                match position {
+                    NumberPosition::Prefix => end.strip_prefix(seperator).is_some_and(|words| is_token(start) && self.matches_words(words)),
+                    NumberPosition::Suffix => start.strip_suffix(seperator).is_some_and(|words| is_token(end) && self.matches_words(words)),
+                }
+            })
+    }
+
+    /// Returns whether the candidate is a cased choice of adjectives and noun
+    fn matches_words(&self, candidate: &str) -> bool {
+        let seperator = self.word_seperator().to_lowercase();
+        self.find_words(candidate, &candidate.to_lowercase(), &seperator, &mut Vec::new())
+    }
+
+    /// Matches the lowercase remainder of a candidate against the adjectives
+    /// still to be chosen and then the noun
+    fn find_words(&self, candidate: &str, rest: &str, seperator: &str, chosen: &mut Vec<usize>) -> bool {
+        let adjective_only = self.structure == Structure::AdjectiveOnly;
+        if chosen.len() == self.adjective_slots() {
+            return (0..self.noun_slots()).any(|noun| {
+                (if adjective_only { rest.is_empty() } else { self.nouns[noun].to_lowercase() == rest })
+                    && self.allows(chosen, noun)
+                    && self.case(self.words(chosen, noun)) == candidate
+            });
+        }
+
+        let last = adjective_only && chosen.len() + 1 == self.adjective_slots();
+        let leading = self.leading_words();
+        (0..leading.len()).any(|i| {
+            let adjective = leading[i].to_lowercase();
+            let rest = rest.strip_prefix(adjective.as_str());
+            match rest.and_then(|rest| if last { Some(rest) } else { rest.strip_prefix(seperator) }) {
+                Some(rest) => {
+                    chosen.push(i);
+                    let found = self.find_words(candidate, rest, seperator, chosen);
+                    chosen.pop();
+                    found
+                },
+                None => false,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Generator<ThreadRng> {
+    fn default() -> Self {
+        Self::with_rng(rand::thread_rng())
+    }
+}
+#[cfg(feature = "std")]
+impl Default for Generator<OsRng> {
+    fn default() -> Self {
+        Self::with_rng(OsRng)
+    }
+}
+#[cfg(feature = "std")]
+impl Default for Generator<StdRng> {
+    fn default() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+}
+#[cfg(feature = "std")]
+impl Default for Generator<SmallRng> {
+    fn default() -> Self {
+        Self::with_rng(SmallRng::from_entropy())
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static DEFAULT_GENERATOR: core::cell::RefCell<SendGenerator> =
+        core::cell::RefCell::new(SendGenerator::default());
+}
+
+#[cfg(feature = "std")]
+/// Returns a name from a default [`SendGenerator`] kept per thread
+///
+/// The generator is seeded from entropy the first time each thread calls this,
+/// and reused afterward, so repeated calls avoid the cost of seeding.
+pub fn next_default() -> String {
+    DEFAULT_GENERATOR.with(|generator| {
+        generator
+            .borrow_mut()
+            .next()
+            .expect("the default generator never gives up")
+    })
+}
+
+impl<R: Rng> Iterator for Generator<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().ok()
+    }
+}
+
+#[cfg(feature = "std")]
+/// A [`Generator`] which never yields the same name twice
+///
+/// Created with [`Generator::unique`]. Emitted names are tracked in memory, so
+/// the iterator ends once the combination space is exhausted, or once
+/// `max_reroll_attempts` consecutive duplicates have been drawn (which can
+/// happen when a [`Length`] shrinks the reachable space).
+#[derive(Clone, Debug)]
+pub struct UniqueGenerator<R: Rng> {
+    generator: Generator<R>,
+    emitted: HashSet<String>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Rng> UniqueGenerator<R> {
+    /// Returns the number of unique names left before the combinations run
+    /// out, saturating at `u128::MAX` like [`Generator::combinations`]
+    pub fn remaining(&self) -> u128 {
+        self.generator.combinations().saturating_sub(self.emitted.len() as u128)
+    }
+
+    /// Returns up to `n` unique names, fewer if the combinations run out
+    pub fn sample(&mut self, n: usize) -> Vec<String> {
+        self.take(n).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Rng> Iterator for UniqueGenerator<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.emitted.len() as u128 >= self.generator.combinations() {
+            return None;
+        }
+
+        for _ in 0..self.generator.max_reroll_attempts.max(1) {
+            let name = self.generator.next()?;
+            if self.emitted.insert(name.clone()) {
+                return Some(name);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "stream")]
+/// An asynchronous stream of names
+///
+/// Created with [`Generator::into_stream`]. Generation never waits on I/O, so
+/// every poll is immediately ready. The stream implements
+/// [`futures::Stream`](Stream), and can also be awaited directly with
+/// [`next`](Self::next) without importing any `futures` traits.
+#[derive(Clone, Debug)]
+pub struct NameStream<R: Rng> {
+    generator: Generator<R>,
+}
+
+#[cfg(feature = "stream")]
+impl<R: Rng> Unpin for NameStream<R> {}
+
+#[cfg(feature = "stream")]
+impl<R: Rng> NameStream<R> {
+    /// Attempts to pull the next name, which is always ready, returning
+    /// `None` once the generator gives up
+    pub fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<String>> {
+        Stream::poll_next(self, cx)
+    }
+
+    /// Returns the next name, or `None` once the generator gives up
+    pub async fn next(&mut self) -> Option<String> {
+        core::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Returns the bounds on the remaining length of the stream
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        Stream::size_hint(self)
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<R: Rng> Stream for NameStream<R> {
+    type Item = String;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<String>> {
+        Poll::Ready(self.get_mut().generator.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.generator.size_hint()
+    }
+}
+
+#[cfg(feature = "std")]
+/// A type-erased RNG, so that generators built from different RNGs share the
+/// type [`BoxedGenerator`]
+///
+/// Clones share the same underlying RNG, so cloning a [`BoxedGenerator`] does
+/// not repeat its sequence of names.
+#[derive(Clone)]
+pub struct BoxedRng(Arc<Mutex<Box<dyn RngCore + Send>>>);
+
+#[cfg(feature = "std")]
+impl BoxedRng {
+    /// Wraps the given RNG
+    pub fn new(rng: Box<dyn RngCore + Send>) -> Self {
+        BoxedRng(Arc::new(Mutex::new(rng)))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Box<dyn RngCore + Send>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for BoxedRng {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("BoxedRng(..)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl RngCore for BoxedRng {
+    fn next_u32(&mut self) -> u32 {
+        self.lock().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.lock().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.lock().fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.lock().try_fill_bytes(dest)
+    }
+}
+
+#[cfg(feature = "std")]
+/// A [`Generator`] whose RNG is chosen at runtime, built with
+/// [`GeneratorBuilder::boxed_rng`]
+pub type BoxedGenerator = Generator<BoxedRng>;
+
+#[cfg(feature = "std")]
+impl GeneratorBuilder<BoxedRng> {
+    /// Uses the given RNG, so that generators built from different RNGs can be
+    /// stored together as [`BoxedGenerator`]s
+    pub fn boxed_rng(&mut self, rng: Box<dyn RngCore + Send>) -> &mut Self {
+        self.rng(BoxedRng::new(rng))
+    }
+}
+
+impl<R: Rng + Clone> Generator<R> {
+    /// Returns a builder with the default adjectives, nouns, naming, casing
+    /// and length set, but no RNG
+    ///
+    /// The configuration can be kept apart from any RNG, which is given later
+    /// with [`GeneratorBuilder::rng`] before building.
+    pub fn default_config() -> GeneratorBuilder<R> {
+        let mut builder = GeneratorBuilder::default();
+        builder
+            .adjectives(ADJECTIVES.iter().copied())
+            .nouns(NOUNS.iter().copied())
+            .naming(Name::default())
+            .casing(Casing::default())
+            .length(Length::default());
+        builder
+    }
+}
+
+impl<R: Rng + SeedableRng> Generator<R> {
+    /// Returns a generator with the default configuration and an RNG seeded
+    /// from the given value, without drawing on the operating system's
+    /// entropy
+    ///
+    /// Unlike [`Default`], this works without the `std` feature and on targets
+    /// such as `wasm32-unknown-unknown`, where entropy is unavailable unless
+    /// `getrandom`'s `js` feature is enabled. The seed can be supplied by the
+    /// host instead, for example from `crypto.getRandomValues` in JavaScript.
+    pub fn from_seed(seed: u64) -> Self {
+        Self::with_rng(R::seed_from_u64(seed))
+    }
+
+    /// Replaces the RNG with one seeded from the given value, so that a pooled
+    /// generator can be reset to a known sequence of names
+    ///
+    /// The generator's own RNG type `R` is reseeded, so the sequence is only
+    /// reproducible across platforms and `rand` releases when `R` is portable,
+    /// such as `rand_chacha`'s `ChaCha8Rng`. The output of [`StdRng`] may
+    /// change between `rand` releases, and that of [`SmallRng`] also differs
+    /// between 32-bit and 64-bit targets.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = R::seed_from_u64(seed);
+    }
+}
+
+impl<R: Rng + SeedableRng + Clone> Generator<R> {
+    /// Returns the name at `index` for the given seed, or `None` if the
+    /// generator gives up
+    ///
+    /// Each call generates from a copy of the generator with a fresh RNG,
+    /// seeded from both `seed` and `index`, so the same pair always maps to
+    /// the same name regardless of what was generated before. Distinct
+    /// indices seed distinct RNGs, but their names may still collide. With a
+    /// [`SendGenerator`] the RNG is a [`StdRng`], and with [`Name::Sequential`]
+    /// the number is `index + 1`.
+    pub fn nth_deterministic(&self, seed: u64, index: u64) -> Option<String> {
+        let mut generator = self.clone();
+        generator.reseed(seed ^ index.wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        generator.sequence = index;
+        generator.next()
+    }
+}
+
+/// A [`Generator`] which yields every name exactly once, in a random order
+///
+/// Created with [`Generator::into_exhaustive`].
+#[derive(Clone, Debug)]
+pub struct ExhaustiveGenerator<R: Rng> {
+    generator: Generator<R>,
+    words: Vec<(Vec<usize>, usize)>,
+    order: Vec<(usize, Option<u128>)>,
+}
+
+impl<R: Rng> Iterator for ExhaustiveGenerator<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((word, number)) = self.order.pop() {
+            let (adjectives, noun) = &self.words[word];
+            let name = self.generator.render(adjectives, *noun, number);
+            if let Some(name) = self.generator.fit_length(name).filter(|name| !self.generator.blocked(name)) {
+                self.generator.sequence = self.generator.sequence.wrapping_add(1);
+                return Some(name);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.generator.length {
+            Length::None | Length::Truncate(_) | Length::TruncateBytes(_) if self.generator.blocklist.is_empty() => {
+                (self.order.len(), Some(self.order.len()))
+            },
+            _ => (0, Some(self.order.len())),
+        }
+    }
+}
+
+/// A [`Generator`] which repeats a shuffled order of every name forever
+///
+/// Created with [`Generator::into_cycle`].
+#[derive(Clone, Debug)]
+pub struct CycleGenerator<R: Rng> {
+    generator: Generator<R>,
+    words: Vec<(Vec<usize>, usize)>,
+    order: Vec<(usize, Option<u128>)>,
+    position: usize,
+}
+
+impl<R: Rng> Iterator for CycleGenerator<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // give up after a whole cycle of rejected names
+        for _ in 0..self.order.len() {
+            let (word, number) = self.order[self.position];
+            self.position = (self.position + 1) % self.order.len();
+            let (adjectives, noun) = &self.words[word];
+            let name = self.generator.render(adjectives, *noun, number);
+            if let Some(name) = self.generator.fit_length(name).filter(|name| !self.generator.blocked(name)) {
+                self.generator.sequence = self.generator.sequence.wrapping_add(1);
+                return Some(name);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.generator.length {
+            _ if self.order.is_empty() => (0, Some(0)),
+            Length::None | Length::Truncate(_) | Length::TruncateBytes(_) if self.generator.blocklist.is_empty() => (usize::MAX, None),
+            _ => (0, None),
+        }
+    }
+}
+
+/// Chooses an index into a list of length `len`, uniformly unless weights are
+/// given
+fn choose_index<R: Rng + ?Sized>(rng: &mut R, len: usize, weights: Option<&[u32]>) -> Option<usize> {
+    match weights {
+        Some(weights) => Some(WeightedIndex::new(weights).ok()?.sample(rng)),
+        None if len > 0 => Some(rng.gen_range(0..len)),
+        None => None,
+    }
+}
+
+/// Chooses `count` indices into a list of length `len`, which are distinct
+/// when the list is long enough and unweighted
+fn choose_indices<R: Rng + ?Sized>(rng: &mut R, len: usize, count: usize, weights: Option<&[u32]>) -> Option<Vec<usize>> {
+    if let Some(weights) = weights {
+        let distribution = WeightedIndex::new(weights).ok()?;
+        Some((0..count).map(|_| distribution.sample(rng)).collect())
+    } else if count <= len {
+        Some(index::sample(rng, len, count).into_vec())
+    } else if len > 0 {
+        Some((0..count).map(|_| rng.gen_range(0..len)).collect())
+    } else {
+        None
+    }
+}
+
+/// Retains the words, and their weights, for which `keep` returns `true`
+fn retain_words(words: &mut Vec<Cow<'static, str>>, weights: &mut Option<Vec<u32>>, keep: impl Fn(&str) -> bool) {
+    if let Some(weights) = weights {
+        *weights = words
+            .iter()
+            .zip(weights.iter())
+            .filter(|(word, _)| keep(word))
+            .map(|(_, &weight)| weight)
+            .collect();
+    }
+    words.retain(|word| keep(word));
+}
+
+/// Removes all but the first copy of each word, along with their weights
+fn dedup_words(words: &mut Vec<Cow<'static, str>>, weights: &mut Option<Vec<u32>>) {
+    let mut seen = BTreeSet::new();
+    let first = words.iter().map(|word| seen.insert(word.clone())).collect::<Vec<_>>();
+
+    if let Some(weights) = weights {
+        *weights = weights.iter().zip(&first).filter(|(_, &first)| first).map(|(&weight, _)| weight).collect();
+    }
+    let mut first = first.into_iter();
+    words.retain(|_| first.next().unwrap_or(false));
+}
+
+/// Applies the transform to a word, borrowing it when there is none
+fn transform_word<'a>(transform: Option<&Transform>, word: &'a str) -> Cow<'a, str> {
+    match transform {
+        Some(Transform(transform)) => Cow::Owned(transform(word)),
+        None => Cow::Borrowed(word),
+    }
+}
+
+/// Estimates the number of syllables of a word by counting its groups of
+/// vowels, discounting a silent trailing `e`
+///
+/// ```
+/// assert_eq!(2, names::count_syllables("rusty"));
+/// assert_eq!(1, names::count_syllables("cake"));
+/// ```
+pub fn count_syllables(word: &str) -> usize {
+    let is_vowel = |c: char| "aeiouy".contains(c.to_ascii_lowercase());
+    let chars = word.chars().collect::<Vec<_>>();
+    let mut syllables = chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, &c)| is_vowel(c) && (i == 0 || !is_vowel(chars[i - 1])))
+        .count();
+
+    let lowercase = word.to_lowercase();
+    if syllables > 1 && lowercase.ends_with('e') && !lowercase.ends_with("le") && !lowercase.ends_with("ee") {
+        syllables -= 1;
+    }
+    syllables.max(usize::from(!word.is_empty()))
+}
+
+/// Converts a name into a DNS label, which is empty if no valid characters
+/// remain
+fn to_dns_label(name: &str) -> String {
+    let mut label = name
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '-')
+        .collect::<String>();
+    label = label.trim_start_matches('-').to_string();
+    label.truncate(63);
+    label.trim_end_matches('-').to_string()
+}
+
+/// Returns the lowercase first letter of a word
+fn initial(word: &str) -> Option<char> {
+    word.chars().next().and_then(|c| c.to_lowercase().next())
+}
+
+fn truncate_chars(s: &mut String, x: usize) {
+    #[cfg(feature = "length-unicode")]
+    let index = s.grapheme_indices(true).nth(x).map(|(index, _)| index);
+    #[cfg(not(feature = "length-unicode"))]
+    let index = s.char_indices().nth(x).map(|(index, _)| index);
+    if let Some(index) = index {
+        s.truncate(index);
+    }
+}
+
+/// Returns the length of the string as counted by [`Length`], in grapheme
+/// clusters with the `length-unicode` feature and in `char`s otherwise
+fn text_len(s: &str) -> usize {
+    #[cfg(feature = "length-unicode")]
+    return s.graphemes(true).count();
+    #[cfg(not(feature = "length-unicode"))]
+    return s.chars().count();
+}
+
+fn truncate_bytes(s: &mut String, x: usize) {
+    if x < s.len() {
+        let index = (0..=x).rev().find(|&i| s.is_char_boundary(i)).unwrap_or(0);
+        s.truncate(index);
+    }
+}
+
+fn generate_number_with_x_digits<R: Rng + ?Sized>(x: usize, rng: &mut R) -> u64 {
+    let lower_bound = 10u64.pow((x - 1) as u32);
+    let upper_bound = 10u64.pow(x as u32) - 1;
+    rng.gen_range(lower_bound..=upper_bound)
+}
+
+fn generate_hex_with_x_digits<R: Rng + ?Sized>(x: usize, rng: &mut R) -> String {
+    (0..x)
+        .map(|_| char::from_digit(rng.gen_range(0..16), 16).unwrap())
+        .collect()
+}
+
+fn generate_encoded_with_x_symbols<R: Rng + ?Sized>(x: usize, symbols: &[char], rng: &mut R) -> String {
+    (0..x).map(|_| symbols[rng.gen_range(0..symbols.len())]).collect()
+}
+
+/// Writes `number` in the base of the alphabet, zero-padded to `x` symbols
+fn encode_with_x_symbols(mut number: u128, x: usize, symbols: &[char]) -> String {
+    let base = symbols.len() as u128;
+    let mut encoded = vec![symbols[0]; x];
+    for symbol in encoded.iter_mut().rev() {
+        *symbol = symbols[(number % base) as usize];
+        number /= base;
+    }
+    encoded.into_iter().collect()
+}
+
+#[cfg(feature = "std")]
+fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+// Name::Timestamped is rejected when building without the std feature, so
+// this only gives an unvalidated generator a neutral width instead of panicking
+#[cfg(not(feature = "std"))]
+fn unix_timestamp() -> u64 {
+    0
+}
+
+fn pow_saturating(base: u128, exp: usize) -> u128 {
+    u32::try_from(exp)
+        .ok()
+        .and_then(|exp| base.checked_pow(exp))
+        .unwrap_or(u128::MAX)
+}
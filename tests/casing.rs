@@ -0,0 +1,421 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::sync::Arc;
+
+use common::rusty_nail;
+use names::{Casing, Error, GeneratorBuilder, NumberSeperator, StdRng, ThreadRng, WordTransform, TITLE_CASE_STOP_WORDS};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+
+#[test]
+fn from_str() {
+    let cases = [
+        ("lower", Casing::Lowercase(NumberSeperator::Dash)),
+        ("upper", Casing::Uppercase(NumberSeperator::Dash)),
+        ("capitalize", Casing::Capitalize(NumberSeperator::Dash)),
+        ("capitalize-first", Casing::CapitalizeFirst(NumberSeperator::Dash)),
+        ("capitalize-last", Casing::CapitalizeLast(NumberSeperator::Dash)),
+        ("snake", Casing::SnakeCase),
+        ("screaming-snake", Casing::ScreamingSnakeCase),
+        ("capitalized-snake", Casing::CapitalizedSnakeCase),
+        ("camel", Casing::CamelCase),
+        ("pascal", Casing::PascalCase),
+        ("kebab", Casing::KebabCase),
+        ("screaming-kebab", Casing::ScreamingKebabCase),
+        ("capitalized-kebab", Casing::CapitalizedKebabCase),
+        ("title", Casing::TitleCase(NumberSeperator::Dash)),
+        ("dot", Casing::DotCase),
+        ("train", Casing::TrainCase),
+        ("flat", Casing::FlatCase),
+        ("alternating", Casing::AlternatingCase(NumberSeperator::Dash)),
+    ];
+
+    for (s, casing) in cases {
+        assert_eq!(casing, s.parse::<Casing>().unwrap());
+    }
+}
+
+#[test]
+fn from_str_unknown() {
+    assert!(matches!(
+        "sponge".parse::<Casing>(),
+        Err(Error::UnknownCasing(s)) if s == "sponge"
+    ));
+}
+
+fn title(adjectives: &[&str], nouns: &[&str], stop_words: Option<Vec<String>>) -> String {
+    let mut builder = GeneratorBuilder::default();
+    builder
+        .adjectives(adjectives.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+        .nouns(nouns.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+        .casing(Casing::TitleCase(NumberSeperator::Custom(" ".into())))
+        .rng(ThreadRng::default());
+    if let Some(stop_words) = stop_words {
+        builder.title_case_stop_words(stop_words);
+    }
+    builder.build().unwrap().next().unwrap()
+}
+
+#[test]
+fn title_case() {
+    assert_eq!("Return of the King", title(&["return of the"], &["king"], None));
+    assert_eq!("The Rusty Nail", title(&["the rusty"], &["nail"], None));
+    assert_eq!("Rusty The", title(&["RUSTY"], &["the"], None));
+}
+
+#[test]
+fn title_case_custom_stop_words() {
+    assert_eq!(
+        "Return Of the king Nail",
+        title(&["return of the"], &["king nail"], Some(vec!["the".into(), "king".into()]))
+    );
+}
+
+#[test]
+fn dot_case() {
+    assert_eq!("rusty.nail", Casing::DotCase.apply(vec!["Rusty", "nail"]));
+    assert_eq!(".", Casing::DotCase.seperator());
+}
+
+#[test]
+fn train_case() {
+    assert_eq!("Rusty-Nail", Casing::TrainCase.apply(vec!["rusty", "NAIL"]));
+    assert_eq!("-", Casing::TrainCase.seperator());
+}
+
+#[test]
+fn flat_case() {
+    assert_eq!("rustynail", Casing::FlatCase.apply(vec!["Rusty", "NAIL"]));
+    assert_eq!("", Casing::FlatCase.seperator());
+}
+
+#[test]
+fn alternating_case() {
+    let casing = Casing::AlternatingCase(NumberSeperator::Dash);
+
+    assert_eq!("rUsTy-NaIl", casing.apply(vec!["Rusty", "NAIL"]));
+    assert_eq!("rUsT1y_NaIl", Casing::AlternatingCase(NumberSeperator::Underscore).apply(vec!["rust1y", "nail"]));
+    assert_eq!("-", casing.seperator());
+}
+
+#[test]
+fn preserve_all_caps_words() {
+    let generate = |casing: Casing, preserve: bool| {
+        GeneratorBuilder::default()
+            .adjectives(vec!["rusty".to_string()])
+            .nouns(vec!["API".to_string()])
+            .casing(casing)
+            .preserve_all_caps_words(preserve)
+            .rng(ThreadRng::default())
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+    };
+    let cases = [
+        (Casing::Capitalize(NumberSeperator::Dash), "Rusty-API", "Rusty-Api"),
+        (Casing::CamelCase, "rustyAPI", "rustyApi"),
+        (Casing::Lowercase(NumberSeperator::Custom("X".into())), "rustyxAPI", "rustyxapi"),
+        (Casing::SnakeCase, "rusty_API", "rusty_api"),
+        (Casing::TitleCase(NumberSeperator::Dash), "Rusty-API", "Rusty-Api"),
+        (Casing::AlternatingCase(NumberSeperator::Dash), "rUsTy-API", "rUsTy-ApI"),
+        (Casing::ScreamingKebabCase, "RUSTY-API", "RUSTY-API"),
+    ];
+
+    for (casing, preserved, cased) in cases {
+        assert_eq!(preserved, generate(casing.clone(), true), "{:?}", casing);
+        assert_eq!(cased, generate(casing.clone(), false), "{:?}", casing);
+    }
+}
+
+#[test]
+fn normalize_case() {
+    let mut builder = rusty_nail();
+    builder
+        .adjectives(["Rusty", "rusty", "SHINY"])
+        .nouns(["NaIl"])
+        .casing(Casing::custom(|word, _| word.to_string(), "-"))
+        .dedup_dictionaries(true);
+
+    // a casing which keeps each word as it is shows the words before casing
+    let generator = builder.normalize_case(true).build().unwrap();
+    assert_eq!(["rusty", "shiny"], generator.adjectives());
+    assert_eq!(["nail"], generator.nouns());
+    for name in generator.take(20) {
+        assert!(name == "rusty-nail" || name == "shiny-nail", "{}", name);
+    }
+    let generator = builder.normalize_case(false).build().unwrap();
+    assert_eq!(3, generator.adjectives().len());
+    assert!(generator.take(20).any(|name| name == "SHINY-NaIl"));
+
+    // all-caps words are lowercased before preserve_all_caps_words sees them
+    let name = rusty_nail()
+        .nouns(["API"])
+        .casing(Casing::Capitalize(NumberSeperator::Dash))
+        .preserve_all_caps_words(true)
+        .normalize_case(true)
+        .build()
+        .unwrap()
+        .next();
+    assert_eq!(Some("Rusty-Api".to_string()), name);
+}
+
+#[test]
+fn custom_spongebob_case() {
+    // Alternates per word rather than per letter, starting lowercase on odd words
+    let spongebob = Casing::custom(
+        |word, i| {
+            word.chars()
+                .enumerate()
+                .map(|(j, c)| if (i + j) % 2 == 1 { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() })
+                .collect()
+        },
+        " ",
+    );
+    assert_eq!("rUsTy NaIl oF", spongebob.apply(vec!["rusty", "nail", "of"]));
+    assert_eq!(" ", spongebob.seperator());
+    assert_eq!(spongebob, spongebob.clone());
+    assert!(serde_json::to_string(&spongebob).is_err());
+
+    let mut generator = rusty_nail()
+        .casing(spongebob)
+        .build()
+        .unwrap();
+    let name = generator.next().unwrap();
+    assert_eq!("rUsTy NaIl", name);
+    assert!(generator.matches(&name));
+}
+
+#[test]
+fn capitalized_snake_and_kebab_case() {
+    assert_eq!("Rusty_Nail", Casing::CapitalizedSnakeCase.apply(vec!["rusty", "NAIL"]));
+    assert_eq!("Big_Rusty_Nail", Casing::CapitalizedSnakeCase.apply(vec!["big", "rUSTY", "nail"]));
+    assert_eq!("_", Casing::CapitalizedSnakeCase.seperator());
+
+    assert_eq!("Rusty-Nail", Casing::CapitalizedKebabCase.apply(vec!["rusty", "NAIL"]));
+    assert_eq!("Big-Rusty-Nail", Casing::CapitalizedKebabCase.apply(vec!["big", "rUSTY", "nail"]));
+    assert_eq!("-", Casing::CapitalizedKebabCase.seperator());
+}
+
+#[test]
+fn space_seperator() {
+    assert_eq!(NumberSeperator::Space, " ".parse::<NumberSeperator>().unwrap());
+    assert_eq!(" ", NumberSeperator::Space.to_string());
+    assert_eq!("\" \"", serde_json::to_string(&NumberSeperator::Space).unwrap());
+    assert_eq!(NumberSeperator::Space, serde_json::from_str::<NumberSeperator>("\" \"").unwrap());
+
+    let casing = Casing::Capitalize(NumberSeperator::Space);
+    assert_eq!("capitalize( )", casing.to_string());
+    assert_eq!(casing, "capitalize( )".parse::<Casing>().unwrap());
+
+    let name = rusty_nail()
+        .casing(casing)
+        .build()
+        .unwrap()
+        .next()
+        .unwrap();
+    assert_eq!("Rusty Nail", name);
+}
+
+#[test]
+fn serde_round_trip() {
+    for casing in [Casing::DotCase, Casing::TrainCase, Casing::FlatCase, Casing::CapitalizedSnakeCase, Casing::CapitalizedKebabCase] {
+        let json = serde_json::to_string(&casing).unwrap();
+        assert_eq!(casing, serde_json::from_str::<Casing>(&json).unwrap());
+    }
+}
+
+#[test]
+fn transform_runs_before_casing() {
+    let generate = |transform: WordTransform, casing: Casing| {
+        rusty_nail()
+            .transform(Some(transform))
+            .casing(casing)
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+    };
+
+    let upper: WordTransform = Arc::new(|word| word.to_uppercase());
+    assert_eq!("rusty-nail", generate(upper, Casing::Lowercase(NumberSeperator::Dash)));
+
+    let strip_vowels: WordTransform = Arc::new(|word| word.chars().filter(|c| !"aeiou".contains(*c)).collect());
+    assert_eq!("RstyNl", generate(strip_vowels, Casing::PascalCase));
+}
+
+#[test]
+fn display_round_trips() {
+    let seperators = [
+        NumberSeperator::Dash,
+        NumberSeperator::Underscore,
+        NumberSeperator::Space,
+        NumberSeperator::None,
+        NumberSeperator::Custom(".".into()),
+    ];
+    let mut casings = vec![
+        Casing::SnakeCase,
+        Casing::ScreamingSnakeCase,
+        Casing::CamelCase,
+        Casing::PascalCase,
+        Casing::KebabCase,
+        Casing::ScreamingKebabCase,
+        Casing::DotCase,
+        Casing::TrainCase,
+        Casing::FlatCase,
+        Casing::CapitalizedSnakeCase,
+        Casing::CapitalizedKebabCase,
+    ];
+    for seperator in seperators {
+        casings.push(Casing::Lowercase(seperator.clone()));
+        casings.push(Casing::Uppercase(seperator.clone()));
+        casings.push(Casing::Capitalize(seperator.clone()));
+        casings.push(Casing::CapitalizeFirst(seperator.clone()));
+        casings.push(Casing::CapitalizeLast(seperator.clone()));
+        casings.push(Casing::TitleCase(seperator.clone()));
+        casings.push(Casing::AlternatingCase(seperator));
+    }
+
+    for casing in casings {
+        assert_eq!(Ok(casing.clone()), casing.to_string().parse::<Casing>());
+    }
+}
+
+#[test]
+fn display() {
+    assert_eq!("lower", Casing::Lowercase(NumberSeperator::Dash).to_string());
+    assert_eq!("title(_)", Casing::TitleCase(NumberSeperator::Underscore).to_string());
+    assert_eq!("upper()", Casing::Uppercase(NumberSeperator::None).to_string());
+    assert_eq!("screaming-snake", Casing::ScreamingSnakeCase.to_string());
+    assert!(matches!("snake(_)".parse::<Casing>(), Err(Error::UnknownCasing(_))));
+}
+
+#[test]
+fn apply_matches_reference() {
+    // mixes ASCII with characters whose case mapping changes their length or
+    // depends on context, such as "ß", "İ" and the final sigma
+    const PIECES: &[&str] = &["rusty", "NAIL", "of", "The", "ß", "İ", "ΟΔΟΣ", "Σ", "é", "1", " ", "-", "_", ""];
+    let seperators = || {
+        vec![
+            NumberSeperator::Dash,
+            NumberSeperator::Underscore,
+            NumberSeperator::None,
+            NumberSeperator::Custom("X".into()),
+            NumberSeperator::Custom("Σ".into()),
+        ]
+    };
+    let mut casings = vec![
+        Casing::SnakeCase,
+        Casing::ScreamingSnakeCase,
+        Casing::CamelCase,
+        Casing::PascalCase,
+        Casing::KebabCase,
+        Casing::ScreamingKebabCase,
+        Casing::DotCase,
+        Casing::TrainCase,
+        Casing::FlatCase,
+        Casing::CapitalizedSnakeCase,
+        Casing::CapitalizedKebabCase,
+    ];
+    for seperator in seperators() {
+        casings.push(Casing::Lowercase(seperator.clone()));
+        casings.push(Casing::Uppercase(seperator.clone()));
+        casings.push(Casing::Capitalize(seperator.clone()));
+        casings.push(Casing::CapitalizeFirst(seperator.clone()));
+        casings.push(Casing::CapitalizeLast(seperator.clone()));
+        casings.push(Casing::TitleCase(seperator.clone()));
+        casings.push(Casing::AlternatingCase(seperator));
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..500 {
+        let words = (0..rng.gen_range(0..5))
+            .map(|_| (0..rng.gen_range(0..4)).map(|_| *PIECES.choose(&mut rng).unwrap()).collect::<String>())
+            .collect::<Vec<_>>();
+        let words = words.iter().map(String::as_str).collect::<Vec<_>>();
+        for casing in &casings {
+            assert_eq!(reference(casing, words.clone()), casing.apply(words.clone()), "{:?} {:?}", casing, words);
+        }
+    }
+}
+
+/// The straightforward implementation of [`Casing::apply`], joining separately
+/// cased words, which the buffered implementation must match byte for byte
+fn reference(casing: &Casing, words: Vec<&str>) -> String {
+    let capitalize = |word: &str| {
+        let mut c = word.chars();
+        match c.next() {
+            None => String::new(),
+            Some(f) => f.to_uppercase().collect::<String>() + c.as_str().to_lowercase().as_str(),
+        }
+    };
+    let last = words.len().saturating_sub(1);
+    let each = |seperator: &str, case: &dyn Fn(usize, &str) -> String| {
+        words.iter().enumerate().map(|(i, word)| case(i, word)).collect::<Vec<_>>().join(seperator)
+    };
+
+    match casing {
+        Casing::Lowercase(seperator) => words.join(seperator.to_string().as_str()).to_lowercase(),
+        Casing::Uppercase(seperator) => words.join(seperator.to_string().as_str()).to_uppercase(),
+        Casing::Capitalize(seperator) => each(&seperator.to_string(), &|_, word| capitalize(word)),
+        Casing::CapitalizeFirst(seperator) => each(&seperator.to_string(), &|i, word| {
+            if i == 0 { capitalize(word) } else { word.to_lowercase() }
+        }),
+        Casing::CapitalizeLast(seperator) => each(&seperator.to_string(), &|i, word| {
+            if i == last { capitalize(word) } else { word.to_lowercase() }
+        }),
+        Casing::SnakeCase => words.join("_").to_lowercase(),
+        Casing::ScreamingSnakeCase => words.join("_").to_uppercase(),
+        Casing::CamelCase => each("", &|i, word| if i == 0 { word.to_lowercase() } else { capitalize(word) }),
+        Casing::PascalCase => each("", &|_, word| capitalize(word)),
+        Casing::KebabCase => words.join("-").to_lowercase(),
+        Casing::ScreamingKebabCase => words.join("-").to_uppercase(),
+        Casing::TitleCase(seperator) => {
+            let count = words.iter().map(|word| word.split_whitespace().count()).sum::<usize>();
+            let mut index = 0;
+            words
+                .iter()
+                .map(|word| {
+                    word.split_whitespace()
+                        .map(|token| {
+                            let lowercase = token.to_lowercase();
+                            let edge = index == 0 || index + 1 == count;
+                            index += 1;
+                            if !edge && TITLE_CASE_STOP_WORDS.contains(&lowercase.as_str()) {
+                                lowercase
+                            } else {
+                                capitalize(token)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>()
+                .join(seperator.to_string().as_str())
+        },
+        Casing::DotCase => words.join(".").to_lowercase(),
+        Casing::TrainCase | Casing::CapitalizedKebabCase => each("-", &|_, word| capitalize(word)),
+        Casing::CapitalizedSnakeCase => each("_", &|_, word| capitalize(word)),
+        Casing::FlatCase => words.join("").to_lowercase(),
+        Casing::AlternatingCase(seperator) => {
+            let mut upper = false;
+            let mut cased = Vec::new();
+            for word in &words {
+                let mut word_cased = String::new();
+                for c in word.chars() {
+                    let alphabetic = c.is_alphabetic();
+                    if alphabetic && upper {
+                        word_cased.extend(c.to_uppercase());
+                    } else {
+                        word_cased.extend(c.to_lowercase());
+                    }
+                    upper ^= alphabetic;
+                }
+                cased.push(word_cased);
+            }
+            cased.join(seperator.to_string().as_str())
+        },
+        Casing::Custom { per_word, separator } => each(separator, &|i, word| (per_word.0)(word, i)),
+    }
+}
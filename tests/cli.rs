@@ -0,0 +1,165 @@
+#![cfg(feature = "application")]
+
+use std::{
+    env, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process::{self, Command, Output},
+};
+
+use regex::Regex;
+
+fn names(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_names"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn lines(output: &Output) -> Vec<String> {
+    String::from_utf8(output.stdout.clone())
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn casing() {
+    let pattern = Regex::new("^[A-Z][a-z]+[A-Z][a-z]+$").unwrap();
+    let output = names(&["--casing", "pascal", "3"]);
+
+    assert!(output.status.success());
+    let lines = lines(&output);
+    assert_eq!(3, lines.len());
+    for line in lines {
+        assert!(pattern.is_match(&line), "{} is not pascal case", line);
+    }
+}
+
+#[test]
+fn default_casing() {
+    let pattern = Regex::new("^[a-z]+-[a-z]+$").unwrap();
+    let output = names(&[]);
+
+    assert!(output.status.success());
+    assert!(pattern.is_match(&lines(&output)[0]));
+}
+
+#[test]
+fn unknown_casing() {
+    let output = names(&["--casing", "sponge"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown casing"));
+}
+
+/// A word list in the temporary directory, which is removed when dropped
+struct WordFile(PathBuf);
+
+impl Deref for WordFile {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for WordFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn word_file(name: &str, contents: &str) -> WordFile {
+    let path = env::temp_dir().join(format!("names-{}-{}.txt", process::id(), name));
+    fs::write(&path, contents).unwrap();
+    WordFile(path)
+}
+
+#[test]
+fn custom_dictionaries() {
+    let adjectives = word_file("adjectives", "# adjectives\nimaginary\n\n  \ngolden\n");
+    let nouns = word_file("nouns", "roll\n");
+    let output = names(&[
+        "--adjectives",
+        adjectives.to_str().unwrap(),
+        "--nouns",
+        nouns.to_str().unwrap(),
+        "10",
+    ]);
+
+    assert!(output.status.success());
+    for line in lines(&output) {
+        assert!(line == "imaginary-roll" || line == "golden-roll", "{}", line);
+    }
+}
+
+#[test]
+fn missing_dictionary() {
+    let output = names(&["--nouns", "/does/not/exist.txt"]);
+
+    assert_eq!(Some(1), output.status.code());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("/does/not/exist.txt"));
+}
+
+#[test]
+fn seed() {
+    let first = names(&["--seed", "42", "--number", "3", "5"]);
+    let second = names(&["--seed", "42", "--number", "3", "5"]);
+
+    assert!(first.status.success());
+    assert_eq!(5, lines(&first).len());
+    assert_eq!(lines(&first), lines(&second));
+}
+
+#[test]
+fn empty_dictionary() {
+    let adjectives = word_file("empty-adjectives", "\n  \n");
+    let output = names(&["--adjectives", adjectives.to_str().unwrap()]);
+
+    assert_eq!(Some(1), output.status.code());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error: adjectives must not be empty"), "{}", stderr);
+    assert!(!stderr.contains("panicked"), "{}", stderr);
+}
+
+#[test]
+fn separator() {
+    let pattern = Regex::new("^[a-z]+-[a-z]+_[0-9]{3}$").unwrap();
+    let output = names(&["--number", "3", "--separator", "_"]);
+
+    assert!(output.status.success());
+    let line = &lines(&output)[0];
+    assert!(pattern.is_match(line), "{}", line);
+}
+
+#[test]
+fn default_separator() {
+    let pattern = Regex::new("^[a-z]+-[a-z]+-[0-9]{3}$").unwrap();
+    let output = names(&["--number", "3"]);
+
+    assert!(output.status.success());
+    let line = &lines(&output)[0];
+    assert!(pattern.is_match(line), "{}", line);
+}
+
+#[test]
+fn unique() {
+    let adjectives = word_file("unique-adjectives", "imaginary\ngolden\n");
+    let nouns = word_file("unique-nouns", "roll\n");
+    let output = names(&[
+        "--unique",
+        "--adjectives",
+        adjectives.to_str().unwrap(),
+        "--nouns",
+        nouns.to_str().unwrap(),
+        "5",
+    ]);
+
+    assert!(output.status.success());
+    let mut lines = lines(&output);
+    lines.sort();
+    assert_eq!(vec!["golden-roll", "imaginary-roll"], lines);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("only 2 unique names"));
+}
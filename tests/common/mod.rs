@@ -0,0 +1,8 @@
+use names::{GeneratorBuilder, StdRng};
+use rand::SeedableRng;
+
+pub fn rusty_nail() -> GeneratorBuilder<StdRng> {
+    let mut builder = GeneratorBuilder::default();
+    builder.adjectives(["rusty"]).nouns(["nail"]).rng(StdRng::seed_from_u64(0));
+    builder
+}
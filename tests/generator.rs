@@ -0,0 +1,1086 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::{borrow::Cow, collections::BTreeSet, io::Cursor};
+
+use common::rusty_nail;
+use names::{
+    BoxedGenerator, Casing, Error, Generator, GeneratorBuilder, Length, Locale, Name,
+    NumberSeperator, SendGenerator, SmallRng, StdRng, Structure, ThreadRng, ADJECTIVES, ADJECTIVES_ES,
+    ADJECTIVE_COUNT, BUILTIN_COMBINATIONS, NOUNS, NOUNS_ES, NOUN_COUNT,
+};
+use rand::SeedableRng;
+
+fn small_builder() -> GeneratorBuilder<StdRng> {
+    let mut builder = GeneratorBuilder::default();
+    builder.adjectives(["rusty", "shiny"]).nouns(["nail", "pin", "bolt"]).seed(0);
+    builder
+}
+
+fn small(naming: Name) -> Generator<StdRng> {
+    small_builder().naming(naming).build().unwrap()
+}
+
+#[test]
+fn combinations() {
+    let sep = NumberSeperator::Dash;
+
+    assert_eq!(6, small(Name::Plain).combinations());
+    assert_eq!(6 * 9, small(Name::Numbered(1, sep.clone())).combinations());
+    assert_eq!(6 * 900, small(Name::Numbered(3, sep.clone())).combinations());
+    assert_eq!(6 * 1000, small(Name::ZeroPaddedNumbered(3, sep.clone())).combinations());
+    assert_eq!(6 * 65536, small(Name::HexSuffixed(4, sep.clone())).combinations());
+    assert_eq!(6, small(Name::Prefixed("svc".into(), sep.clone())).combinations());
+    assert_eq!(6, small(Name::Suffixed("prod".into(), sep)).combinations());
+}
+
+#[test]
+fn builtin_words_are_borrowed() {
+    let borrowed = |generator: &Generator<StdRng>| {
+        generator.adjectives().iter().chain(generator.nouns()).all(|word| matches!(word, Cow::Borrowed(_)))
+    };
+
+    let generator = GeneratorBuilder::default().seed(0).build().unwrap();
+    assert!(borrowed(&generator));
+    assert_eq!(ADJECTIVES, generator.adjectives());
+    assert_eq!(NOUNS, generator.nouns());
+
+    let generator = GeneratorBuilder::default().locale(Locale::Es).seed(0).build().unwrap();
+    assert!(borrowed(&generator));
+
+    let generator = GeneratorBuilder::default().adjectives(vec!["rusty".to_string()]).seed(0).build().unwrap();
+    assert!(!borrowed(&generator));
+}
+
+#[test]
+fn builtin_counts() {
+    const _: () = assert!(ADJECTIVE_COUNT > 0 && NOUN_COUNT > 0);
+    const _: () = assert!(BUILTIN_COMBINATIONS == ADJECTIVE_COUNT as u128 * NOUN_COUNT as u128);
+
+    assert_eq!(ADJECTIVES.len(), ADJECTIVE_COUNT);
+    assert_eq!(NOUNS.len(), NOUN_COUNT);
+    assert_eq!(Generator::<ThreadRng>::default().combinations(), BUILTIN_COMBINATIONS);
+}
+
+#[test]
+fn combinations_saturate() {
+    let generator = small(Name::HexSuffixed(64, NumberSeperator::Dash));
+
+    assert_eq!(u128::MAX, generator.combinations());
+}
+
+#[test]
+fn zero_padded_numbered_has_leading_zeros() {
+    let generator = small(Name::ZeroPaddedNumbered(2, NumberSeperator::Dash));
+
+    assert!(generator.take(1000).any(|name| name.rsplit('-').next().unwrap().starts_with('0')));
+}
+
+#[test]
+fn collision_probability() {
+    let generator = small(Name::Plain);
+    let close = |expected: f64, actual: f64| (expected - actual).abs() < 1e-12;
+
+    assert_eq!(0.0, generator.collision_probability(0));
+    assert_eq!(0.0, generator.collision_probability(1));
+    // 1 - exp(-2 / 12)
+    assert!(close(0.153_518_275_109_385_9, generator.collision_probability(2)));
+    // 1 - exp(-6 / 12)
+    assert!(close(0.393_469_340_287_366_6, generator.collision_probability(3)));
+    assert!(close(1.0, generator.collision_probability(100)));
+}
+
+#[test]
+fn collision_probability_of_a_huge_space() {
+    let generator = small(Name::HexSuffixed(64, NumberSeperator::Dash));
+
+    assert!(generator.collision_probability(1_000_000) < 1e-20);
+}
+
+#[test]
+fn unique_drains_the_space() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "shiny".to_string()])
+        .nouns(vec!["nail".to_string(), "pin".to_string()])
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    let mut unique = generator.unique();
+
+    let mut names: Vec<_> = unique.by_ref().take(4).collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(4, names.len());
+    assert_eq!(None, unique.next());
+}
+
+#[test]
+fn unique_with_zero_reroll_attempts() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "shiny".to_string()])
+        .nouns(vec!["nail".to_string(), "pin".to_string()])
+        .max_reroll_attempts(0)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    let mut unique = generator.unique();
+
+    // zero attempts still makes one, like the other rerolls
+    assert!(unique.next().is_some());
+}
+
+#[test]
+fn unique_remaining_counts_down() {
+    let mut unique = small(Name::Plain).unique();
+
+    for remaining in (0..6).rev() {
+        unique.next().unwrap();
+        assert_eq!(remaining, unique.remaining());
+    }
+    assert_eq!(None, unique.next());
+}
+
+#[test]
+fn unique_remaining_saturates() {
+    let unique = small(Name::HexSuffixed(64, NumberSeperator::Dash)).unique();
+
+    assert_eq!(u128::MAX, unique.remaining());
+}
+
+#[test]
+fn iterate_the_built_in_lists() {
+    let generator = GeneratorBuilder::default().seed(0).build().unwrap();
+
+    assert_eq!(ADJECTIVES.len(), generator.adjectives_iter().count());
+    assert_eq!(NOUNS.len(), generator.nouns_iter().count());
+    assert!(generator.adjectives_iter().eq(ADJECTIVES.iter().copied()));
+    assert!(generator.nouns_iter().eq(NOUNS.iter().copied()));
+}
+
+#[test]
+fn read_the_word_lists() {
+    let generator = small(Name::Plain);
+
+    assert_eq!(["rusty", "shiny"], generator.adjectives());
+    assert_eq!(["nail", "pin", "bolt"], generator.nouns());
+}
+
+#[test]
+fn add_words_extends_the_built_in_lists() {
+    let generator = GeneratorBuilder::default()
+        .add_adjectives(vec!["imaginary".to_string()])
+        .add_nouns(vec!["roll".to_string()])
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(ADJECTIVES.len() + 1, generator.adjectives_iter().count());
+    assert!(generator.adjectives_iter().any(|word| word == "imaginary"));
+    assert!(generator.adjectives_iter().any(|word| word == ADJECTIVES[0]));
+    assert_eq!(NOUNS.len() + 1, generator.nouns_iter().count());
+    assert!(generator.nouns_iter().any(|word| word == "roll"));
+}
+
+#[test]
+fn add_words_extends_custom_lists() {
+    let mut generator = rusty_nail().add_adjectives(["shiny"]).build().unwrap();
+
+    let mut names = generator.sample(50);
+    names.sort();
+    names.dedup();
+    assert_eq!(vec!["rusty-nail", "shiny-nail"], names);
+}
+
+#[test]
+fn add_words_still_rejects_empty_lists() {
+    let result = GeneratorBuilder::default()
+        .nouns(Vec::<String>::new())
+        .add_nouns(Vec::<String>::new())
+        .rng(StdRng::seed_from_u64(0))
+        .build();
+
+    assert_eq!(Some(Error::NounsEmpty), result.err());
+}
+
+#[test]
+fn errors_compare_by_value() {
+    assert_eq!(Error::NounsEmpty, Error::NounsEmpty);
+    assert_ne!(Error::NounsEmpty, Error::AdjectivesEmpty);
+    assert_eq!(Error::ValidationError("bad".to_string()), Error::ValidationError("bad".to_string()));
+    assert_ne!(Error::ValidationError("bad".to_string()), Error::ValidationError("worse".to_string()));
+    assert_eq!(Error::UninitializedField("rng"), Error::UninitializedField("rng"));
+    assert_ne!(Error::TooManyDigits(20), Error::TooManyDigits(21));
+}
+
+#[test]
+fn locale_selects_the_built_in_lists() {
+    let mut generator = GeneratorBuilder::default()
+        .locale(Locale::Es)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    for name in generator.sample(50) {
+        let (adjective, noun) = name.split_once('-').unwrap();
+        assert!(ADJECTIVES_ES.contains(&adjective), "{}", name);
+        assert!(NOUNS_ES.contains(&noun), "{}", name);
+    }
+}
+
+#[test]
+fn locale_defaults_to_english() {
+    let generator = GeneratorBuilder::default().seed(0).build().unwrap();
+
+    assert_eq!(Locale::default().adjectives(), ADJECTIVES);
+    assert_eq!(ADJECTIVES.len(), generator.adjectives_iter().count());
+}
+
+#[test]
+fn count_syllables() {
+    let cases = [
+        ("", 0),
+        ("nail", 1),
+        ("the", 1),
+        ("cake", 1),
+        ("rhythm", 1),
+        ("rusty", 2),
+        ("table", 2),
+        ("banana", 3),
+        ("beautiful", 3),
+        ("imaginary", 5),
+    ];
+
+    for (word, syllables) in cases {
+        assert_eq!(syllables, names::count_syllables(word), "{}", word);
+    }
+}
+
+#[test]
+fn max_syllables_rerolls_long_names() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(["imaginary", "red", "beautiful"])
+        .nouns(["elephant", "cat"])
+        .max_syllables(2)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    let mut names = generator.sample(20);
+    names.sort();
+    names.dedup();
+    assert_eq!(vec!["red-cat"], names);
+}
+
+#[test]
+fn max_syllables_gives_up_when_unreachable() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["imaginary".to_string()])
+        .nouns(vec!["elephant".to_string()])
+        .max_syllables(3)
+        .max_reroll_attempts(10)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(None, generator.next());
+}
+
+#[test]
+fn ascii_only_removes_non_ascii_words() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(["rusty", "pequeño", "grün"])
+        .nouns(["nail", "árbol"])
+        .ascii_only(true)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(["rusty"], generator.adjectives());
+    for name in generator.sample(10) {
+        assert!(name.is_ascii(), "{}", name);
+    }
+
+    let result = GeneratorBuilder::default()
+        .nouns(["árbol"])
+        .ascii_only(true)
+        .rng(StdRng::seed_from_u64(0))
+        .build();
+    assert!(matches!(result, Err(Error::NounsEmpty)));
+}
+
+#[test]
+fn dedup_dictionaries_removes_duplicates() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(["rusty", "shiny", "rusty", "dull", "shiny"])
+        .nouns(["nail", "nail", "pin"])
+        .noun_weights(vec![1, 2, 3])
+        .dedup_dictionaries(true)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(generator.adjectives(), ["rusty", "shiny", "dull"]);
+    assert_eq!(generator.nouns(), ["nail", "pin"]);
+    assert_eq!(3 * 2, generator.combinations());
+}
+
+#[test]
+fn send_generators_across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SendGenerator>();
+    assert_send_sync::<Generator<SmallRng>>();
+
+    let handles = (0..4)
+        .map(|seed| {
+            let mut generator: Generator<StdRng> = GeneratorBuilder::default().seed(seed).build().unwrap();
+            std::thread::spawn(move || generator.sample(3))
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(3, handle.join().unwrap().len());
+    }
+}
+
+#[test]
+fn boxed_generators_in_a_collection() {
+    let mut generators: Vec<BoxedGenerator> = vec![
+        GeneratorBuilder::default().boxed_rng(Box::new(StdRng::seed_from_u64(1))).build().unwrap(),
+        GeneratorBuilder::default().boxed_rng(Box::new(SmallRng::seed_from_u64(2))).build().unwrap(),
+    ];
+
+    let expected: Vec<_> = GeneratorBuilder::default().seed(1).build().unwrap().take(3).collect();
+    assert_eq!(expected, generators[0].sample(3));
+    assert_eq!(3, generators[1].sample(3).len());
+}
+
+#[test]
+fn reseed_restarts_the_sequence() {
+    let mut generator = GeneratorBuilder::default().seed(42).build().unwrap();
+    let first = generator.sample(5);
+
+    generator.reseed(42);
+    assert_eq!(first, generator.sample(5));
+
+    let mut small: Generator<SmallRng> = GeneratorBuilder::default()
+        .rng(SmallRng::seed_from_u64(1))
+        .build()
+        .unwrap();
+    let first = small.sample(5);
+    small.reseed(1);
+    assert_eq!(first, small.sample(5));
+}
+
+#[test]
+fn default_config() {
+    let config = Generator::<StdRng>::default_config();
+    assert!(matches!(config.clone().build(), Err(Error::UninitializedField("rng"))));
+
+    let mut generator = config.clone().rng(StdRng::seed_from_u64(5)).build().unwrap();
+    let mut expected = GeneratorBuilder::default().seed(5).build().unwrap();
+    assert_eq!(expected.sample(10), generator.sample(10));
+    assert_eq!(expected.combinations(), generator.combinations());
+}
+
+#[test]
+fn set_rng() {
+    let mut generator = SendGenerator::default();
+    generator.set_rng(StdRng::seed_from_u64(7));
+    let first = generator.sample(5);
+
+    let mut other = SendGenerator::default();
+    other.sample(3);
+    other.set_rng(StdRng::seed_from_u64(7));
+    assert_eq!(first, other.sample(5));
+}
+
+#[test]
+fn next_into_matches_next() {
+    let mut builder = GeneratorBuilder::default();
+    builder.seed(3);
+    let namings = [
+        Name::Plain,
+        Name::Numbered(4, NumberSeperator::Dash),
+        Name::ZeroPaddedNumbered(3, NumberSeperator::Underscore),
+        Name::Prefixed("svc".into(), NumberSeperator::Dash),
+        Name::HexSuffixed(6, NumberSeperator::None),
+        Name::Sequential(NumberSeperator::Dash),
+    ];
+
+    for naming in namings {
+        for casing in [Casing::default(), Casing::ScreamingSnakeCase, Casing::TitleCase(NumberSeperator::Dash)] {
+            builder.naming(naming.clone()).casing(casing);
+            let expected = builder.build().unwrap().sample(20);
+            let mut generator = builder.build().unwrap();
+            let mut buf = String::from("leftover");
+            for expected in &expected {
+                assert!(generator.next_into(&mut buf));
+                assert_eq!(expected, &buf);
+            }
+        }
+    }
+
+    // "a-nail" and "rusty-nail" are 6 and 10 characters long
+    let mut generator = rusty_nail()
+        .add_adjectives(["a"])
+        .length(Length::Reroll(8))
+        .max_reroll_attempts(10)
+        .build()
+        .unwrap();
+    let mut buf = String::from("leftover");
+    assert!(!generator.next_into(&mut buf));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn dictionary_membership_ignores_case() {
+    let generator = SendGenerator::default();
+    let adjective = ADJECTIVES[0];
+    let noun = NOUNS[0];
+
+    for word in [adjective.to_string(), adjective.to_uppercase(), capitalize(adjective)] {
+        assert!(generator.is_adjective(&word), "{}", word);
+    }
+    for word in [noun.to_string(), noun.to_uppercase(), capitalize(noun)] {
+        assert!(generator.is_noun(&word), "{}", word);
+    }
+    assert!(!generator.is_adjective("NotAWord"));
+    assert!(!generator.is_noun(""));
+
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["Naïve".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .seed(0)
+        .build()
+        .unwrap();
+    assert!(generator.is_adjective("NAÏVE"));
+    assert!(!generator.is_adjective("nail"));
+    assert!(!generator.is_noun("naïve"));
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+#[test]
+fn next_matching() {
+    let mut generator = GeneratorBuilder::default().seed(0).build().unwrap();
+    let starts_with_vowel = |name: &str| name.starts_with(['a', 'e', 'i', 'o', 'u']);
+
+    for _ in 0..100 {
+        let name = generator.next_matching(starts_with_vowel).unwrap();
+        assert!(starts_with_vowel(&name), "{}", name);
+    }
+
+    let mut generator = GeneratorBuilder::default().max_reroll_attempts(10).seed(0).build().unwrap();
+    assert_eq!(None, generator.next_matching(|_| false));
+    assert_eq!(10, generator.last_attempt_count());
+}
+
+#[test]
+fn next_parts() {
+    let mut builder = GeneratorBuilder::default();
+    builder.casing(Casing::ScreamingSnakeCase).seed(5);
+
+    let mut generator = builder.naming(Name::Numbered(4, NumberSeperator::Dash)).build().unwrap();
+    let expected = generator.clone().sample(20);
+    for expected in expected {
+        let parts = generator.next_parts().unwrap();
+        assert_eq!(expected, parts.formatted);
+        assert!(generator.is_adjective(&parts.adjective), "{:?}", parts);
+        assert!(generator.is_noun(&parts.noun), "{:?}", parts);
+        let name = format!("{}_{}-{}", parts.adjective, parts.noun, parts.number.unwrap()).to_uppercase();
+        assert_eq!(name, parts.formatted);
+    }
+
+    let mut generator = builder.naming(Name::Prefixed("svc".into(), NumberSeperator::Dash)).adjective_count(2).build().unwrap();
+    let parts = generator.next_parts().unwrap();
+    assert_eq!(None, parts.number);
+    assert_eq!(2, parts.adjective.split(' ').count(), "{:?}", parts);
+    assert!(parts.formatted.starts_with("SVC-"), "{:?}", parts);
+}
+
+#[test]
+fn rng_mut_interleaves_draws() {
+    use rand::Rng;
+
+    let interleaved = || {
+        let mut generator = GeneratorBuilder::default().seed(11).build().unwrap();
+        (0..5)
+            .map(|_| {
+                let roll = generator.rng_mut().gen_range(1..=6);
+                (roll, generator.next().unwrap())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let first = interleaved();
+    assert_eq!(first, interleaved());
+    assert!(first.iter().all(|(roll, _)| (1..=6).contains(roll)));
+
+    let names = first.into_iter().map(|(_, name)| name).collect::<Vec<_>>();
+    assert_ne!(GeneratorBuilder::default().seed(11).build().unwrap().sample(5), names);
+}
+
+#[test]
+fn draw_unique() {
+    let mut generator = small(Name::Plain);
+
+    let mut names = generator.draw_unique(6).unwrap();
+    names.sort();
+    names.dedup();
+    assert_eq!(6, names.len());
+    assert_eq!(Vec::<String>::new(), generator.draw_unique(0).unwrap());
+}
+
+#[test]
+fn draw_unique_oversubscribed() {
+    let mut generator = small(Name::Plain);
+
+    assert!(matches!(generator.draw_unique(7), Err(Error::Exhausted)));
+}
+
+#[test]
+fn draw_unique_when_names_run_out() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "dull".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .length(Length::Reroll(10))
+        .max_reroll_attempts(50)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert!(matches!(generator.draw_unique(2), Err(Error::Exhausted)));
+    assert!(matches!(generator.draw_unique(1), Ok(names) if names == ["rusty-nail"]));
+}
+
+#[test]
+fn reject_separator_in_words() {
+    let mut builder = rusty_nail();
+    builder.add_adjectives(["hot-dog"]).reject_separator_in_words(true);
+
+    assert!(matches!(
+        builder.build(),
+        Err(Error::SeparatorInWord(word)) if word == "hot-dog"
+    ));
+    assert!(builder.casing(Casing::SnakeCase).build().is_ok());
+    assert!(builder.casing(Casing::CamelCase).build().is_ok());
+    assert!(builder.casing(Casing::default()).reject_separator_in_words(false).build().is_ok());
+}
+
+#[test]
+fn same_seed_same_names() {
+    let draw = || {
+        GeneratorBuilder::default()
+            .seed(42)
+            .build()
+            .unwrap()
+            .take(5)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(draw(), draw());
+}
+
+#[test]
+fn adjective_count() {
+    let generator = GeneratorBuilder::default()
+        .adjective_count(2)
+        .seed(0)
+        .build()
+        .unwrap();
+
+    for name in generator.take(100) {
+        let segments: Vec<_> = name.split('-').collect();
+        assert_eq!(3, segments.len());
+        assert_ne!(segments[0], segments[1]);
+    }
+}
+
+#[test]
+fn adjective_count_exceeding_list() {
+    let mut generator = rusty_nail()
+        .adjective_count(3)
+        .build()
+        .unwrap();
+
+    assert_eq!("rusty-rusty-rusty-nail", generator.next().unwrap());
+    assert_eq!(1, generator.combinations());
+}
+
+#[test]
+fn adjective_count_combinations() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        .nouns(vec!["x".to_string()])
+        .adjective_count(2)
+        .seed(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(6, generator.combinations());
+}
+
+#[test]
+fn length_bounds() {
+    let builder = || {
+        let mut builder = GeneratorBuilder::default();
+        builder
+            .adjectives(vec!["red".to_string(), "shiny".to_string()])
+            .nouns(vec!["pin".to_string(), "bolt".to_string()])
+            .rng(StdRng::seed_from_u64(0));
+        builder
+    };
+
+    assert_eq!((7, 10), builder().build().unwrap().length_bounds());
+    assert_eq!((13, 14), builder().adjective_count(2).build().unwrap().length_bounds());
+
+    let generator = builder().naming(Name::Numbered(3, NumberSeperator::Dash)).build().unwrap();
+    let (min, max) = generator.length_bounds();
+    assert_eq!((11, 14), (min, max));
+    for name in generator.take(50) {
+        assert!((min..=max).contains(&name.len()), "{}", name);
+    }
+}
+
+#[test]
+fn structure() {
+    let mut builder = small_builder();
+    builder.naming(Name::Numbered(2, NumberSeperator::Dash));
+    let cases = [
+        (Structure::AdjectiveNoun, 1, 3, 6),
+        (Structure::AdjectiveNoun, 2, 4, 6),
+        (Structure::NounOnly, 2, 2, 3),
+        (Structure::AdjectiveOnly, 1, 2, 2),
+        (Structure::AdjectiveOnly, 2, 3, 2),
+        (Structure::DoubleNoun, 1, 3, 6),
+        (Structure::DoubleNoun, 2, 3, 6),
+    ];
+
+    for (structure, adjective_count, segments, words) in cases {
+        let generator = builder.structure(structure).adjective_count(adjective_count).build().unwrap();
+        assert_eq!(words * 90, generator.combinations(), "{:?}", structure);
+        for name in generator.clone().take(20) {
+            assert_eq!(segments, name.split('-').count(), "{}", name);
+            assert!(generator.matches(&name), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn double_noun() {
+    let nouns = ["nail", "pin", "bolt"];
+    let generator = GeneratorBuilder::default()
+        .nouns(nouns.iter().map(|noun| noun.to_string()).collect::<Vec<_>>())
+        .structure(Structure::DoubleNoun)
+        .naming(Name::Plain)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    assert_eq!(6, generator.combinations());
+    for name in generator.take(50) {
+        let (first, second) = name.split_once('-').unwrap();
+        assert!(nouns.contains(&first) && nouns.contains(&second), "{}", name);
+        assert_ne!(first, second);
+    }
+
+    // repeated nouns are removed, so they are not counted twice
+    let generator = GeneratorBuilder::default()
+        .nouns(["nail", "pin", "nail"])
+        .structure(Structure::DoubleNoun)
+        .naming(Name::Plain)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    assert_eq!(2, generator.combinations());
+    assert_eq!(
+        BTreeSet::from(["nail-pin".to_string(), "pin-nail".to_string()]),
+        generator.take(50).collect::<BTreeSet<_>>()
+    );
+
+    let result = GeneratorBuilder::default()
+        .nouns(vec!["nail".to_string(), "nail".to_string()])
+        .structure(Structure::DoubleNoun)
+        .rng(StdRng::seed_from_u64(0))
+        .build();
+    assert!(matches!(result, Err(Error::TooFewNouns)));
+}
+
+#[test]
+fn nth_deterministic() {
+    let mut generator = GeneratorBuilder::default().seed(0).build().unwrap();
+    let names = (0..100).map(|index| generator.nth_deterministic(42, index).unwrap()).collect::<Vec<_>>();
+
+    // generating in between, or from another generator, does not change them
+    generator.sample(10);
+    let other = GeneratorBuilder::default().seed(7).build().unwrap();
+    for index in (0..100).rev() {
+        assert_eq!(names[index as usize], generator.nth_deterministic(42, index).unwrap());
+        assert_eq!(names[index as usize], other.nth_deterministic(42, index).unwrap());
+    }
+
+    let distinct = names.iter().collect::<std::collections::HashSet<_>>().len();
+    assert!(distinct > 90, "{}", distinct);
+    assert_ne!(names[0], generator.nth_deterministic(43, 0).unwrap());
+}
+
+#[test]
+fn blocklist_removes_words() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "Shiny".to_string()])
+        .nouns(vec!["nail".to_string(), "pin".to_string()])
+        .blocklist(vec!["SHINY".to_string()])
+        .seed(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(["rusty"], generator.adjectives());
+    for name in generator.take(20) {
+        assert!(name.starts_with("rusty-"), "{}", name);
+    }
+}
+
+#[test]
+fn blocklist_rerolls_combinations() {
+    let mut builder = rusty_nail();
+    builder.add_nouns(["pin"]).naming(Name::Prefixed("bad".into(), NumberSeperator::Dash));
+
+    let generator = builder.casing(Casing::PascalCase).blocklist(vec!["rustynail".to_string()]).build().unwrap();
+    for name in generator.take(20) {
+        assert_eq!("Bad-RustyPin", name);
+    }
+
+    let mut generator = builder.casing(Casing::default()).blocklist(vec!["Bad".to_string()]).build().unwrap();
+    assert_eq!(None, generator.next());
+}
+
+#[test]
+fn borrowing_iterators() {
+    let mut generator = small(Name::Sequential(NumberSeperator::Dash));
+
+    let mut count = 0;
+    for name in &mut generator {
+        count += 1;
+        assert!(name.ends_with(&format!("-{}", count)), "{}", name);
+        if count == 5 {
+            break;
+        }
+    }
+    for name in generator.iter_mut().take(5) {
+        count += 1;
+        assert!(name.ends_with(&format!("-{}", count)), "{}", name);
+    }
+
+    assert!(generator.next().unwrap().ends_with("-11"));
+}
+
+#[test]
+fn try_next() {
+    assert!(small(Name::Plain).try_next().is_ok());
+
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "rusty".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .length(Length::Reroll(8))
+        .max_reroll_attempts(10)
+        .seed(0)
+        .build()
+        .unwrap();
+    assert!(matches!(generator.try_next(), Err(Error::RerollLimitExceeded)));
+    assert_eq!(None, generator.next());
+
+    // the only adjective left after filtering has no weight, so none can be drawn
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "rusty".to_string()])
+        .adjective_weights(vec![1, 0])
+        .min_word_len(2)
+        .seed(0)
+        .build()
+        .unwrap();
+    assert!(matches!(generator.try_next(), Err(Error::EmptyIterator)));
+    assert_eq!(None, generator.next());
+}
+
+#[test]
+fn next_default() {
+    let check = || {
+        let generator = Generator::<ThreadRng>::default();
+        for _ in 0..1000 {
+            let name = names::next_default();
+            assert!(generator.matches(&name), "{}", name);
+        }
+    };
+
+    check();
+    std::thread::scope(|scope| {
+        scope.spawn(check);
+        scope.spawn(check);
+    });
+}
+
+#[test]
+fn distinct_words() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["happy".to_string(), "sad".to_string()])
+        .nouns(vec!["happy".to_string(), "sad".to_string()])
+        .distinct_words(true)
+        .seed(0)
+        .build()
+        .unwrap();
+
+    for name in generator.take(20) {
+        assert!(name == "happy-sad" || name == "sad-happy", "{}", name);
+    }
+}
+
+#[test]
+fn distinct_words_impossible() {
+    let result = GeneratorBuilder::default()
+        .adjectives(vec!["x".to_string()])
+        .nouns(vec!["x".to_string()])
+        .distinct_words(true)
+        .seed(0)
+        .build();
+
+    assert!(matches!(result, Err(Error::IndistinctWords)));
+}
+
+#[test]
+fn weighted_nouns() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string()])
+        .nouns(vec!["nail".to_string(), "pin".to_string()])
+        .noun_weights(vec![99, 1])
+        .seed(0)
+        .build()
+        .unwrap();
+
+    let nails = generator.take(1000).filter(|name| name == "rusty-nail").count();
+    assert!(nails > 950, "only {} nails", nails);
+}
+
+#[test]
+fn weights_must_match_words() {
+    let result = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string()])
+        .adjective_weights(vec![1, 2])
+        .seed(0)
+        .build();
+    assert!(matches!(result, Err(Error::AdjectiveWeightsMismatch)));
+
+    let result = GeneratorBuilder::default().noun_weights(vec![1]).seed(0).build();
+    assert!(matches!(result, Err(Error::NounWeightsMismatch)));
+}
+
+#[test]
+fn sample() {
+    let mut generator = Generator::<StdRng>::default();
+
+    assert_eq!(5, generator.sample(5).len());
+}
+
+#[test]
+fn sample_unique_stops_early() {
+    let mut unique = small(Name::Plain).unique();
+
+    assert_eq!(6, unique.sample(10).len());
+    assert!(unique.sample(1).is_empty());
+}
+
+#[test]
+fn join() {
+    let mut generator = small(Name::Plain);
+
+    assert_eq!("", generator.join(0, ", "));
+    assert_eq!(1, generator.join(1, ", ").split(", ").count());
+    let joined = generator.join(5, ", ");
+    assert_eq!(4, joined.matches(", ").count(), "{}", joined);
+}
+
+#[test]
+fn join_stops_early() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "rusty".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .length(Length::Reroll(8))
+        .max_reroll_attempts(5)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert_eq!("", generator.join(3, ", "));
+}
+
+#[test]
+fn alliterative() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["brave".to_string(), "calm".to_string(), "eager".to_string()])
+        .nouns(vec!["Bear".to_string(), "cat".to_string(), "dog".to_string()])
+        .alliterative(true)
+        .seed(0)
+        .build()
+        .unwrap();
+
+    for name in generator.take(20) {
+        assert!(name == "brave-bear" || name == "calm-cat", "{}", name);
+    }
+}
+
+#[test]
+fn alliterative_impossible() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["eager".to_string()])
+        .nouns(vec!["bear".to_string(), "cat".to_string()])
+        .alliterative(true)
+        .max_reroll_attempts(10)
+        .seed(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(None, generator.next());
+}
+
+#[test]
+fn max_word_len() {
+    let generator = GeneratorBuilder::default().max_word_len(4).seed(0).build().unwrap();
+
+    for name in generator.take(100) {
+        for word in name.split('-') {
+            assert!(word.len() <= 4, "{}", name);
+        }
+    }
+}
+
+#[test]
+fn min_word_len() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "rusty".to_string()])
+        .nouns(vec!["pin".to_string(), "nail".to_string()])
+        .noun_weights(vec![1, 1])
+        .min_word_len(4)
+        .seed(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(vec!["rusty-nail"; 3], generator.take(3).collect::<Vec<_>>());
+}
+
+#[test]
+fn words_from_reader() {
+    let adjectives = "# adjectives\n\n  rusty  \nbig\r\n\t# indented comment\n   \n";
+    let nouns = "nail\n#pin\n";
+    let generator = GeneratorBuilder::default()
+        .adjectives_from_reader(Cursor::new(adjectives))
+        .unwrap()
+        .nouns_from_reader(Cursor::new(nouns))
+        .unwrap()
+        .seed(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(["rusty", "big"], generator.adjectives());
+    assert_eq!(["nail"], generator.nouns());
+}
+
+#[test]
+fn words_from_failing_reader() {
+    let result = GeneratorBuilder::<StdRng>::default()
+        .adjectives_from_reader(Cursor::new(b"rusty\n\xff\n".as_slice()))
+        .map(|_| ());
+
+    assert!(matches!(result, Err(Error::Io(_))));
+}
+
+#[test]
+fn min_combinations() {
+    let builder = || {
+        let mut builder = GeneratorBuilder::default();
+        builder
+            .adjectives(vec!["a".to_string(), "big".to_string(), "rusty".to_string()])
+            .nouns(vec!["pin".to_string(), "nail".to_string()])
+            .min_combinations(4)
+            .seed(0);
+        builder
+    };
+    assert_eq!(6, builder().build().unwrap().combinations());
+
+    // filtering leaves 1 adjective and 1 noun
+    let result = builder().min_word_len(4).build();
+    assert!(matches!(result, Err(Error::TooFewCombinations(1, 4))));
+
+    // numbers widen the name space back above the minimum
+    assert!(builder().min_word_len(4).naming(Name::Numbered(1, NumberSeperator::Dash)).build().is_ok());
+}
+
+#[test]
+fn word_len_filter_empties_list() {
+    let result = GeneratorBuilder::default().max_word_len(1).seed(0).build();
+
+    assert!(matches!(result, Err(Error::AdjectivesEmpty)));
+}
+
+#[test]
+fn exhaustive_yields_every_name_once() {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "shiny".to_string(), "dull".to_string()])
+        .nouns(vec!["nail".to_string(), "pin".to_string(), "bolt".to_string()])
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    let mut exhaustive = generator.into_exhaustive();
+
+    let mut names: Vec<_> = exhaustive.by_ref().take(9).collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(9, names.len());
+    assert_eq!(None, exhaustive.next());
+}
+
+#[test]
+fn cycle_repeats_a_shuffled_order() {
+    let generator = small(Name::Plain);
+    let n = generator.combinations() as usize;
+    let mut cycle = generator.into_cycle();
+
+    let names: Vec<_> = cycle.by_ref().take(3 * n).collect();
+    let (first, second) = (&names[..n], &names[n..2 * n]);
+    let mut sorted = first.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(n, sorted.len());
+    assert_eq!(first, second);
+    for window in names.windows(n) {
+        let mut window = window.to_vec();
+        window.sort();
+        assert_eq!(sorted, window);
+    }
+    assert_eq!(first, &cycle.take(n).collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn cycle_ends_when_every_name_is_rejected() {
+    let mut cycle = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "rusty".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .length(Length::Reroll(8))
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap()
+        .into_cycle();
+
+    assert_eq!(None, cycle.next());
+}
+
+#[test]
+fn exhaustive_enumerates_small_number_spaces() {
+    let generator = small(Name::Numbered(1, NumberSeperator::Dash));
+    let expected = generator.combinations() as usize;
+
+    let mut names: Vec<_> = generator.into_exhaustive().collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(expected, names.len());
+}
@@ -0,0 +1,224 @@
+mod common;
+
+use std::thread;
+
+use common::rusty_nail;
+use names::{Error, GeneratorBuilder, Length, Name, NumberSeperator, StdRng};
+use rand::SeedableRng;
+
+fn generator(length: Length) -> names::Generator<StdRng> {
+    generator_with_attempts(length, 1000)
+}
+
+fn generator_with_attempts(length: Length, attempts: usize) -> names::Generator<StdRng> {
+    GeneratorBuilder::default()
+        .adjectives(vec!["naïve".to_string()])
+        .nouns(vec!["résumé".to_string()])
+        .length(length)
+        .max_reroll_attempts(attempts)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn truncate_counts_characters() {
+    assert_eq!("naïve-r", generator(Length::Truncate(7)).next().unwrap());
+}
+
+#[test]
+fn truncate_never_splits_a_codepoint() {
+    // "naïve-résumé" is 12 characters but 15 bytes, so byte-based truncation
+    // at 3, 8 or 11 would land in the middle of a codepoint
+    for x in 0..=15 {
+        let name = generator(Length::Truncate(x)).next().unwrap();
+        assert_eq!(name.chars().count(), x.min(12));
+        assert!("naïve-résumé".starts_with(&name));
+    }
+}
+
+#[cfg(feature = "length-unicode")]
+#[test]
+fn length_counts_grapheme_clusters() {
+    // "cafe\u{301}" is "café" spelled with a combining acute accent, so 4
+    // grapheme clusters but 5 chars
+    let mut builder = rusty_nail();
+    builder.adjectives(["cafe\u{301}"]).max_reroll_attempts(10);
+
+    assert_eq!(Some("cafe\u{301}".to_string()), builder.length(Length::Truncate(4)).build().unwrap().next());
+    assert_eq!(Some("caf".to_string()), builder.length(Length::Truncate(3)).build().unwrap().next());
+    assert_eq!(Some("cafe\u{301}-nail".to_string()), builder.length(Length::Reroll(9)).build().unwrap().next());
+    assert_eq!(Some("cafe\u{301}-nail".to_string()), builder.length(Length::Range(8, 9)).build().unwrap().next());
+}
+
+#[cfg(not(feature = "length-unicode"))]
+#[test]
+fn length_counts_chars() {
+    let mut builder = rusty_nail();
+    builder.adjectives(["cafe\u{301}"]).max_reroll_attempts(10);
+
+    assert_eq!(Some("cafe".to_string()), builder.length(Length::Truncate(4)).build().unwrap().next());
+    assert_eq!(Some("cafe\u{301}-nail".to_string()), builder.length(Length::Reroll(10)).build().unwrap().next());
+}
+
+#[test]
+fn truncate_bytes_never_splits_a_codepoint() {
+    // "naïve-résumé" is 15 bytes, "ï" spans bytes 2..4 and "é" bytes 8..10
+    // and 13..15
+    let cases = [
+        (0, ""),
+        (2, "na"),
+        (3, "na"),
+        (4, "naï"),
+        (9, "naïve-r"),
+        (10, "naïve-ré"),
+        (14, "naïve-résum"),
+        (15, "naïve-résumé"),
+        (20, "naïve-résumé"),
+    ];
+
+    for (x, expected) in cases {
+        let name = generator(Length::TruncateBytes(x)).next().unwrap();
+        assert_eq!(expected, name, "{}", x);
+        assert!(name.len() <= x);
+    }
+}
+
+#[test]
+fn reroll_counts_characters() {
+    // each of these words is 4 characters but more than 4 bytes long
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["café".to_string(), "naïve".to_string()])
+        .nouns(vec!["über".to_string(), "résumé".to_string()])
+        .length(Length::Reroll(9))
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    for _ in 0..10 {
+        assert_eq!("café-über", generator.next().unwrap());
+    }
+}
+
+#[test]
+fn reroll_gives_up_on_unreachable_length() {
+    // "a-nail" and "rusty-nail" are 6 and 10 characters long, so 8 is within
+    // the bounds but never generated
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["a".to_string(), "rusty".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .length(Length::Reroll(8))
+        .max_reroll_attempts(50)
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    assert_eq!(None, generator.next());
+}
+
+#[test]
+fn reroll_outside_of_length_bounds() {
+    let mut builder = rusty_nail();
+    builder.add_adjectives(["a"]).naming(Name::Numbered(2, NumberSeperator::Dash));
+
+    // names are between 9 ("a-nail-42") and 13 ("rusty-nail-42") characters long
+    assert!(matches!(builder.length(Length::Reroll(3)).build(), Err(Error::UnreachableLength(Length::Reroll(3), 9, 13))));
+    assert!(matches!(builder.length(Length::Reroll(14)).build(), Err(Error::UnreachableLength(_, 9, 13))));
+    assert!(builder.length(Length::Reroll(9)).build().is_ok());
+    // a range is not checked, generating gives up on it as in range_unreachable
+    assert!(builder.length(Length::Range(1, 8)).build().is_ok());
+    assert!(builder.length(Length::Truncate(3)).build().is_ok());
+}
+
+#[test]
+fn reroll_regenerates_the_number() {
+    // only 100 gives "rusty-nail-" a three digit number, so the number must be
+    // redrawn on every attempt
+    let mut generator = rusty_nail()
+        .naming(Name::numbered_range(1..=100, NumberSeperator::Dash))
+        .length(Length::Reroll(14))
+        .max_reroll_attempts(100_000)
+        .build()
+        .unwrap();
+
+    assert_eq!(Some("rusty-nail-100".to_string()), generator.next());
+}
+
+#[test]
+fn last_attempt_count() {
+    let mut generator = generator(Length::None);
+    assert_eq!(0, generator.last_attempt_count());
+    generator.next().unwrap();
+    assert_eq!(1, generator.last_attempt_count());
+
+    // one in a hundred numbers fits, so most names need many attempts
+    let mut generator = rusty_nail()
+        .naming(Name::numbered_range(1..=100, NumberSeperator::Dash))
+        .length(Length::Reroll(14))
+        .max_reroll_attempts(100_000)
+        .build()
+        .unwrap();
+    let attempts = (0..20)
+        .map(|_| {
+            generator.next().unwrap();
+            generator.last_attempt_count()
+        })
+        .sum::<usize>();
+    assert!(attempts > 20 * 10, "{}", attempts);
+
+    let mut generator = generator_with_attempts(Length::Range(1, 5), 50);
+    assert_eq!(None, generator.next());
+    assert_eq!(50, generator.last_attempt_count());
+}
+
+#[test]
+fn reroll_uses_constant_stack() {
+    // a recursive reroll would overflow this stack long before giving up
+    let handle = thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            GeneratorBuilder::default()
+                .adjectives(vec!["a".to_string(), "rusty".to_string()])
+                .nouns(vec!["nail".to_string()])
+                .naming(Name::Numbered(4, NumberSeperator::Dash))
+                .length(Length::Reroll(13))
+                .max_reroll_attempts(100_000)
+                .rng(StdRng::seed_from_u64(0))
+                .build()
+                .unwrap()
+                .next()
+        })
+        .unwrap();
+
+    assert_eq!(None, handle.join().unwrap());
+}
+
+#[test]
+fn range() {
+    let generator = GeneratorBuilder::default()
+        .length(Length::Range(8, 10))
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    for name in generator.take(100) {
+        assert!((8..=10).contains(&name.chars().count()), "{}", name);
+    }
+}
+
+#[test]
+fn range_unreachable() {
+    let mut generator = generator(Length::Range(1, 5));
+
+    assert_eq!(None, generator.next());
+}
+
+#[test]
+fn range_inverted() {
+    let result = GeneratorBuilder::default()
+        .length(Length::Range(10, 8))
+        .rng(StdRng::seed_from_u64(0))
+        .build();
+
+    assert!(matches!(result, Err(Error::InvalidLengthRange(10, 8))));
+}
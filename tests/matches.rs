@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::rusty_nail;
+use names::{Casing, Generator, GeneratorBuilder, Name, NumberPosition, NumberSeperator, StdRng};
+
+fn generator(naming: Name, casing: Casing) -> Generator<StdRng> {
+    GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "hot dog".to_string()])
+        .nouns(vec!["nail".to_string(), "pin".to_string()])
+        .naming(naming)
+        .casing(casing)
+        .seed(0)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn plain() {
+    let generator = generator(Name::Plain, Casing::default());
+
+    assert!(generator.matches("rusty-nail"));
+    assert!(generator.matches("hot dog-pin"));
+    assert!(!generator.matches("rusty-bolt"));
+    assert!(!generator.matches("Rusty-Nail"));
+    assert!(!generator.matches("rusty_nail"));
+    assert!(!generator.matches("rusty-nail-1"));
+    assert!(!generator.matches(""));
+}
+
+#[test]
+fn numbered() {
+    let generator = generator(Name::Numbered(2, NumberSeperator::Dash), Casing::default());
+
+    assert!(generator.matches("rusty-nail-42"));
+    assert!(!generator.matches("rusty-nail-4"));
+    assert!(!generator.matches("rusty-nail-07"));
+    assert!(!generator.matches("rusty-nail"));
+    assert!(!generator.matches("rusty-nail_42"));
+}
+
+#[test]
+fn numbered_prefix_without_seperator() {
+    let mut generator = rusty_nail()
+        .naming(Name::NumberedRange(1, 100, NumberSeperator::None))
+        .number_position(NumberPosition::Prefix)
+        .casing(Casing::CamelCase)
+        .build()
+        .unwrap();
+
+    assert!(generator.matches("100rustyNail"));
+    assert!(generator.matches("7rustyNail"));
+    assert!(!generator.matches("101rustyNail"));
+    assert!(!generator.matches("007rustyNail"));
+    assert!(!generator.matches("rustyNail7"));
+    for name in generator.sample(20) {
+        assert!(generator.matches(&name), "{}", name);
+    }
+}
+
+#[test]
+fn generated_names_match() {
+    let namings = [
+        Name::Plain,
+        Name::Numbered(3, NumberSeperator::Underscore),
+        Name::ZeroPaddedNumbered(2, NumberSeperator::None),
+        Name::HexSuffixed(4, NumberSeperator::Dash),
+        Name::Prefixed("svc".into(), NumberSeperator::Dash),
+        Name::Suffixed("prod".into(), NumberSeperator::Custom("::".into())),
+    ];
+
+    for naming in namings {
+        for casing in [Casing::default(), Casing::PascalCase, Casing::TitleCase(NumberSeperator::Dash)] {
+            let mut generator = generator(naming.clone(), casing);
+            for name in generator.sample(10) {
+                assert!(generator.matches(&name), "{}", name);
+            }
+        }
+    }
+}
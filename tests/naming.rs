@@ -0,0 +1,588 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::rusty_nail;
+use names::{
+    Casing, Error, Generator, GeneratorBuilder, Name, NumberPosition, NumberSeperator, ThreadRng, MAX_DIGITS,
+};
+use regex::Regex;
+
+#[test]
+fn zero_digits_is_rejected_by_builder() {
+    for naming in [
+        Name::Numbered(0, NumberSeperator::Dash),
+        Name::ZeroPaddedNumbered(0, NumberSeperator::Dash),
+    ] {
+        let result = GeneratorBuilder::default()
+            .naming(naming)
+            .rng(ThreadRng::default())
+            .build();
+
+        assert!(matches!(result, Err(Error::ZeroDigits)));
+    }
+}
+
+#[test]
+fn zero_digits_is_rejected_by_deserialize() {
+    let result = serde_json::from_str::<Generator<ThreadRng>>(
+        r#"{ "naming": { "Numbered": [0, "-"] } }"#,
+    );
+
+    assert!(result.unwrap_err().to_string().contains("at least 1"));
+}
+
+fn generate(naming: Name, casing: Casing) -> String {
+    rusty_nail().naming(naming).casing(casing).build().unwrap().next().unwrap()
+}
+
+#[test]
+fn prefixed() {
+    assert_eq!(
+        "svc-rusty-nail",
+        generate(Name::Prefixed("svc".into(), NumberSeperator::Dash), Casing::default())
+    );
+}
+
+#[test]
+fn suffixed() {
+    assert_eq!(
+        "rusty-nail-prod",
+        generate(Name::Suffixed("prod".into(), NumberSeperator::Dash), Casing::default())
+    );
+}
+
+#[test]
+fn affixes_are_cased() {
+    assert_eq!(
+        "SVC_RUSTY_NAIL",
+        generate(
+            Name::Prefixed("svc".into(), NumberSeperator::Underscore),
+            Casing::ScreamingSnakeCase
+        )
+    );
+    assert_eq!(
+        "Rusty-Nail-Prod",
+        generate(
+            Name::Suffixed("prod".into(), NumberSeperator::Dash),
+            Casing::Capitalize(NumberSeperator::Dash)
+        )
+    );
+}
+
+#[test]
+fn casings_without_a_seperator_drop_the_number_seperator() {
+    for position in [NumberPosition::Suffix, NumberPosition::Prefix] {
+        for (casing, words) in [(Casing::CamelCase, "rustyNail"), (Casing::PascalCase, "RustyNail"), (Casing::FlatCase, "rustynail")] {
+            let mut generator = rusty_nail()
+                .naming(Name::Numbered(2, NumberSeperator::Dash))
+                .number_position(position)
+                .casing(casing.clone())
+                .build()
+                .unwrap();
+            let name = generator.next().unwrap();
+            let number = match position {
+                NumberPosition::Suffix => name.strip_prefix(words),
+                NumberPosition::Prefix => name.strip_suffix(words),
+            };
+
+            assert!(number.is_some_and(|number| number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit())), "{}", name);
+            assert!(generator.matches(&name), "{}", name);
+            assert_eq!((name.len(), name.len()), generator.length_bounds(), "{:?}", casing);
+        }
+    }
+}
+
+#[test]
+fn empty_seperators_keep_the_number_seperator() {
+    let pattern = Regex::new(r"^rustynail-\d{4}$").unwrap();
+    let naming = Name::Numbered(4, NumberSeperator::Dash);
+
+    let name = generate(naming.clone(), Casing::Lowercase(NumberSeperator::None));
+    assert!(pattern.is_match(&name), "{}", name);
+
+    let mut generator = rusty_nail().naming(naming).word_separator(NumberSeperator::None).build().unwrap();
+    let name = generator.next().unwrap();
+    assert!(pattern.is_match(&name), "{}", name);
+}
+
+#[test]
+fn casings_without_a_seperator_keep_the_affix_seperator() {
+    assert_eq!("Svc-RustyNail", generate(Name::Prefixed("svc".into(), NumberSeperator::Dash), Casing::PascalCase));
+    assert_eq!("svc_rustynail", generate(Name::Prefixed("svc".into(), NumberSeperator::Underscore), Casing::FlatCase));
+    assert_eq!("RustyNail-Prod", generate(Name::Suffixed("prod".into(), NumberSeperator::Dash), Casing::PascalCase));
+    assert_eq!("rustyNail.prod", generate(Name::Suffixed("prod".into(), NumberSeperator::Custom(".".into())), Casing::CamelCase));
+
+    let pattern = Regex::new("^RustyNail-[0-9a-f]{4}$").unwrap();
+    let name = generate(Name::HexSuffixed(4, NumberSeperator::Dash), Casing::PascalCase);
+    assert!(pattern.is_match(&name), "{}", name);
+
+    let pattern = Regex::new("^RustyNail_[ab]{3}$").unwrap();
+    let naming = Name::EncodedSuffix { chars: 3, alphabet: "ab".into(), sep: NumberSeperator::Underscore };
+    let name = generate(naming, Casing::PascalCase);
+    assert!(pattern.is_match(&name), "{}", name);
+}
+
+#[test]
+fn casing_and_naming_composition() {
+    let namings = [
+        Name::Plain,
+        Name::NumberedRange(42, 42, NumberSeperator::Dash),
+        Name::NumberedRange(42, 42, NumberSeperator::None),
+        Name::Prefixed("svc".into(), NumberSeperator::Dash),
+        Name::Suffixed("Prod".into(), NumberSeperator::None),
+    ];
+    let cases = [
+        ("lower", ["rusty-nail", "rusty-nail-42", "rusty-nail42", "svc-rusty-nail", "rusty-nailprod"]),
+        ("upper", ["RUSTY-NAIL", "RUSTY-NAIL-42", "RUSTY-NAIL42", "SVC-RUSTY-NAIL", "RUSTY-NAILPROD"]),
+        ("capitalize", ["Rusty-Nail", "Rusty-Nail-42", "Rusty-Nail42", "Svc-Rusty-Nail", "Rusty-NailProd"]),
+        ("capitalize-first", ["Rusty-nail", "Rusty-nail-42", "Rusty-nail42", "Svc-Rusty-nail", "Rusty-nailProd"]),
+        ("capitalize-last", ["rusty-Nail", "rusty-Nail-42", "rusty-Nail42", "Svc-rusty-Nail", "rusty-NailProd"]),
+        ("snake", ["rusty_nail", "rusty_nail-42", "rusty_nail42", "svc-rusty_nail", "rusty_nailprod"]),
+        ("screaming-snake", ["RUSTY_NAIL", "RUSTY_NAIL-42", "RUSTY_NAIL42", "SVC-RUSTY_NAIL", "RUSTY_NAILPROD"]),
+        ("camel", ["rustyNail", "rustyNail42", "rustyNail42", "svc-rustyNail", "rustyNailprod"]),
+        ("pascal", ["RustyNail", "RustyNail42", "RustyNail42", "Svc-RustyNail", "RustyNailProd"]),
+        ("kebab", ["rusty-nail", "rusty-nail-42", "rusty-nail42", "svc-rusty-nail", "rusty-nailprod"]),
+        ("screaming-kebab", ["RUSTY-NAIL", "RUSTY-NAIL-42", "RUSTY-NAIL42", "SVC-RUSTY-NAIL", "RUSTY-NAILPROD"]),
+        ("title", ["Rusty-Nail", "Rusty-Nail-42", "Rusty-Nail42", "Svc-Rusty-Nail", "Rusty-NailProd"]),
+        ("dot", ["rusty.nail", "rusty.nail-42", "rusty.nail42", "svc-rusty.nail", "rusty.nailprod"]),
+        ("train", ["Rusty-Nail", "Rusty-Nail-42", "Rusty-Nail42", "Svc-Rusty-Nail", "Rusty-NailProd"]),
+        ("flat", ["rustynail", "rustynail42", "rustynail42", "svc-rustynail", "rustynailprod"]),
+    ];
+
+    for (casing, expected) in cases {
+        for (naming, expected) in namings.iter().zip(expected) {
+            let name = generate(naming.clone(), casing.parse().unwrap());
+            assert_eq!(expected, name, "{} with {:?}", casing, naming);
+        }
+    }
+}
+
+#[test]
+fn hex_suffixed() {
+    let pattern = Regex::new("^[a-z]+-[a-z]+-[0-9a-f]{4}$").unwrap();
+    let generator = GeneratorBuilder::default()
+        .naming(Name::HexSuffixed(4, NumberSeperator::Dash))
+        .rng(ThreadRng::default())
+        .build()
+        .unwrap();
+
+    for name in generator.take(100) {
+        assert!(pattern.is_match(&name), "{} is not hex suffixed", name);
+    }
+}
+
+fn encoded(alphabet: &str) -> Generator<ThreadRng> {
+    GeneratorBuilder::default()
+        .naming(Name::EncodedSuffix { chars: 6, alphabet: alphabet.into(), sep: NumberSeperator::Dash })
+        .rng(ThreadRng::default())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn encoded_suffix_base36() {
+    let alphabet = "0123456789abcdefghijklmnopqrstuvwxyz";
+    let generator = encoded(alphabet);
+    assert_eq!(36u128.pow(6) * Generator::<ThreadRng>::default().combinations(), generator.combinations());
+
+    for name in generator.take(100) {
+        let (_, suffix) = name.rsplit_once('-').unwrap();
+        assert_eq!(6, suffix.len(), "{}", name);
+        assert!(suffix.chars().all(|c| alphabet.contains(c)), "{}", name);
+    }
+}
+
+#[test]
+fn encoded_suffix_custom_alphabet() {
+    // Crockford base32, without the ambiguous i, l, o and u
+    let alphabet = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    let mut generator = encoded(alphabet);
+
+    for name in generator.sample(100) {
+        let (_, suffix) = name.rsplit_once('-').unwrap();
+        assert_eq!(6, suffix.chars().count(), "{}", name);
+        assert!(suffix.chars().all(|c| alphabet.contains(c)), "{}", name);
+        assert!(generator.matches(&name), "{}", name);
+    }
+}
+
+#[test]
+fn encoded_suffix_is_validated() {
+    let result = rusty_nail()
+        .naming(Name::EncodedSuffix { chars: 4, alphabet: "".into(), sep: NumberSeperator::Dash })
+        .build();
+    assert!(matches!(result, Err(Error::EmptyAlphabet)));
+
+    let result = rusty_nail()
+        .naming(Name::EncodedSuffix { chars: 0, alphabet: "abc".into(), sep: NumberSeperator::Dash })
+        .build();
+    assert!(matches!(result, Err(Error::ZeroDigits)));
+
+    let result = rusty_nail()
+        .naming(Name::EncodedSuffix { chars: 4, alphabet: "abca".into(), sep: NumberSeperator::Dash })
+        .build();
+    assert!(matches!(result, Err(Error::DuplicateSymbol('a'))));
+}
+
+fn build_variable_numbered(min_digits: usize, max_digits: usize) -> Result<Generator<ThreadRng>, Error> {
+    GeneratorBuilder::default()
+        .naming(Name::VariableNumbered { min_digits, max_digits, sep: NumberSeperator::Dash })
+        .rng(ThreadRng::default())
+        .build()
+}
+
+#[test]
+fn variable_numbered() {
+    let mut generator = build_variable_numbered(2, 4).unwrap();
+    let mut seen = [false; 5];
+
+    for name in generator.sample(1000) {
+        let (_, number) = name.rsplit_once('-').unwrap();
+        assert!((2..=4).contains(&number.len()), "{}", name);
+        assert!(number.bytes().all(|b| b.is_ascii_digit()) && !number.starts_with('0'), "{}", name);
+        assert!(generator.matches(&name), "{}", name);
+        seen[number.len()] = true;
+    }
+    assert_eq!([false, false, true, true, true], seen);
+}
+
+#[test]
+fn variable_numbered_is_validated() {
+    assert!(matches!(build_variable_numbered(0, 2), Err(Error::ZeroDigits)));
+    assert!(matches!(build_variable_numbered(3, 2), Err(Error::InvalidDigitRange(3, 2))));
+    assert_eq!(Some(Error::TooManyDigits(MAX_DIGITS + 1)), build_variable_numbered(1, MAX_DIGITS + 1).err());
+    assert!(build_variable_numbered(3, 3).is_ok());
+}
+
+#[test]
+fn digit_counts_up_to_the_maximum() {
+    // 10 digits overflow a 32-bit `usize`, 19 is the most a `u64` holds
+    for x in [1, 10, MAX_DIGITS] {
+        for naming in [Name::Numbered(x, NumberSeperator::Dash), Name::ZeroPaddedNumbered(x, NumberSeperator::Dash)] {
+            let mut generator = rusty_nail()
+                .naming(naming)
+                .build()
+                .unwrap();
+            let name = generator.next().unwrap();
+            let number = name.strip_prefix("rusty-nail-").unwrap();
+
+            assert_eq!(x, number.len(), "{}", name);
+            assert!(number.bytes().all(|b| b.is_ascii_digit()), "{}", name);
+        }
+    }
+}
+
+#[test]
+fn too_many_digits_is_rejected() {
+    for naming in [
+        Name::Numbered(MAX_DIGITS + 1, NumberSeperator::Dash),
+        Name::ZeroPaddedNumbered(MAX_DIGITS + 1, NumberSeperator::Dash),
+    ] {
+        let result = GeneratorBuilder::default()
+            .naming(naming)
+            .rng(ThreadRng::default())
+            .build();
+
+        assert!(matches!(result, Err(Error::TooManyDigits(20))));
+    }
+
+    let result = serde_json::from_str::<Generator<ThreadRng>>(
+        r#"{ "naming": { "Numbered": [20, "-"] } }"#,
+    );
+    assert!(result.unwrap_err().to_string().contains("at most 19"));
+}
+
+#[test]
+fn zero_length_hex_is_rejected() {
+    let result = GeneratorBuilder::default()
+        .naming(Name::HexSuffixed(0, NumberSeperator::Dash))
+        .rng(ThreadRng::default())
+        .build();
+
+    assert!(matches!(result, Err(Error::ZeroDigits)));
+}
+
+fn numbered(position: NumberPosition) -> String {
+    GeneratorBuilder::default()
+        .adjectives(vec!["Rusty".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .naming(Name::Numbered(2, NumberSeperator::Underscore))
+        .casing(Casing::CapitalizeLast(NumberSeperator::Dash))
+        .number_position(position)
+        .rng(ThreadRng::default())
+        .build()
+        .unwrap()
+        .next()
+        .unwrap()
+}
+
+#[test]
+fn number_prefix() {
+    let pattern = Regex::new("^[1-9][0-9]_rusty-Nail$").unwrap();
+    let name = numbered(NumberPosition::Prefix);
+
+    assert!(pattern.is_match(&name), "{}", name);
+}
+
+#[test]
+fn number_suffix() {
+    let pattern = Regex::new("^rusty-Nail_[1-9][0-9]$").unwrap();
+    let name = numbered(NumberPosition::Suffix);
+
+    assert!(pattern.is_match(&name), "{}", name);
+    assert_eq!(NumberPosition::Suffix, NumberPosition::default());
+}
+
+#[test]
+fn word_separator_is_independent_of_number_separator() {
+    let name = rusty_nail()
+        .naming(Name::Numbered(2, NumberSeperator::Dash))
+        .word_separator(".")
+        .build()
+        .unwrap()
+        .next()
+        .unwrap();
+
+    assert!(Regex::new(r"^rusty\.nail-\d{2}$").unwrap().is_match(&name), "{}", name);
+}
+
+#[test]
+fn word_separator_is_deserialized() {
+    let mut generator = serde_json::from_str::<Generator<ThreadRng>>(
+        r#"{ "adjectives": ["rusty"], "nouns": ["nail"], "casing": { "Capitalize": "-" }, "word_separator": "_" }"#,
+    )
+    .unwrap();
+
+    assert_eq!(Some("Rusty_Nail".to_string()), generator.next());
+}
+
+#[test]
+fn numbered_range_stays_in_range() {
+    let mut generator = rusty_nail()
+        .naming(Name::numbered_range(1..=100, NumberSeperator::Dash))
+        .build()
+        .unwrap();
+
+    assert_eq!(100, generator.combinations());
+    for name in generator.sample(1000) {
+        let number: u64 = name.strip_prefix("rusty-nail-").unwrap().parse().unwrap();
+        assert!((1..=100).contains(&number), "{}", name);
+    }
+}
+
+#[test]
+fn numbered_range_of_one_number() {
+    let name = generate(Name::NumberedRange(7, 7, NumberSeperator::Underscore), Casing::default());
+
+    assert_eq!("rusty-nail_7", name);
+}
+
+#[test]
+fn empty_numbered_range_is_rejected() {
+    let result = GeneratorBuilder::default()
+        .naming(Name::NumberedRange(10, 1, NumberSeperator::Dash))
+        .rng(ThreadRng::default())
+        .build();
+    assert!(matches!(result, Err(Error::InvalidNumberRange(10, 1))));
+
+    let result = serde_json::from_str::<Generator<ThreadRng>>(
+        r#"{ "naming": { "NumberedRange": [10, 1, "-"] } }"#,
+    );
+    assert!(result.unwrap_err().to_string().contains("10..=1"));
+}
+
+#[test]
+fn timestamped() {
+    let mut generator = rusty_nail()
+        .naming(Name::Timestamped(NumberSeperator::Dash))
+        .build()
+        .unwrap();
+    let timestamp = |name: String| -> u64 { name.strip_prefix("rusty-nail-").unwrap().parse().unwrap() };
+
+    let first = timestamp(generator.next().unwrap());
+    let second = timestamp(generator.next().unwrap());
+    assert!(first > 1_600_000_000, "{}", first);
+    assert!(first <= second, "{} > {}", first, second);
+}
+
+#[test]
+fn sequential() {
+    let mut generator = rusty_nail()
+        .naming(Name::Sequential(NumberSeperator::Dash))
+        .build()
+        .unwrap();
+
+    let names: Vec<_> = generator.by_ref().take(3).collect();
+    assert_eq!(vec!["rusty-nail-1", "rusty-nail-2", "rusty-nail-3"], names);
+    assert!(generator.matches("rusty-nail-42"));
+    assert!(!generator.matches("rusty-nail-0"));
+}
+
+#[test]
+fn dns_label() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(["Rusty!", "--Shiny", "!!!"].iter().copied().map(String::from).chain(Some("Very".repeat(20))))
+        .nouns(["N@il", "Bolt--", "???"])
+        .casing(Casing::Capitalize(NumberSeperator::Underscore))
+        .naming(Name::Numbered(2, NumberSeperator::Dash))
+        .rng(ThreadRng::default())
+        .build()
+        .unwrap();
+    let valid = Regex::new(r"^[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$").unwrap();
+
+    for _ in 0..100 {
+        let label = generator.dns_label().unwrap();
+        assert!(valid.is_match(&label), "{}", label);
+    }
+}
+
+#[test]
+fn dns_label_rerolls_empty_labels() {
+    let mut generator = GeneratorBuilder::default()
+        .adjectives(vec!["!!!".to_string(), "rusty".to_string()])
+        .nouns(vec!["???".to_string()])
+        .casing(Casing::SnakeCase)
+        .rng(ThreadRng::default())
+        .build()
+        .unwrap();
+
+    for _ in 0..20 {
+        assert_eq!(Some("rusty".to_string()), generator.dns_label());
+    }
+}
+
+#[test]
+fn presets() {
+    let mut docker = GeneratorBuilder::default();
+    let mut heroku = GeneratorBuilder::default();
+    let mut k8s = GeneratorBuilder::default();
+    let cases = [
+        (docker.preset_docker(), r"^[a-z]+-[a-z]+$"),
+        (heroku.preset_heroku(), r"^[a-z]+-[a-z]+-[1-9][0-9]{3}$"),
+        (k8s.preset_k8s(), r"^[a-z0-9]([-a-z0-9]{0,61}[a-z0-9])?$"),
+    ];
+
+    for (builder, pattern) in cases {
+        let regex = Regex::new(pattern).unwrap();
+        let generator = builder.rng(ThreadRng::default()).build().unwrap();
+        for name in generator.take(100) {
+            assert!(regex.is_match(&name), "{} does not match {}", name, pattern);
+        }
+    }
+}
+
+#[test]
+fn from_template() {
+    let mut generator = rusty_nail().build().unwrap();
+    let regex = Regex::new(r"^\{rusty\}_nail_[1-9][0-9]{2}-Nail$").unwrap();
+
+    for _ in 0..20 {
+        let name = generator.from_template("{{{adj}}}_{noun}_{num:3}-Nail").unwrap();
+        assert!(regex.is_match(&name), "{}", name);
+    }
+    assert_eq!("plain", generator.from_template("plain").unwrap());
+}
+
+#[test]
+fn from_template_malformed() {
+    let mut generator = GeneratorBuilder::default().rng(ThreadRng::default()).build().unwrap();
+
+    for template in ["{verb}", "{adj", "adj}", "{num}", "{num:0}", "{num:20}", "{num:x}", "{adj:2}"] {
+        assert!(matches!(generator.from_template(template), Err(Error::InvalidTemplate(_))), "{}", template);
+    }
+}
+
+#[test]
+fn next_with_overrides_the_naming_once() {
+    let mut generator = rusty_nail()
+        .naming(Name::Plain)
+        .build()
+        .unwrap();
+
+    assert_eq!(Some("rusty-nail".to_string()), generator.next());
+    let numbered = generator.next_with(&Name::Numbered(4, NumberSeperator::Dash)).unwrap();
+    assert!(Regex::new(r"^rusty-nail-\d{4}$").unwrap().is_match(&numbered), "{}", numbered);
+    assert_eq!(Name::Plain, generator.config().naming);
+    assert_eq!(Some("rusty-nail".to_string()), generator.next());
+
+    assert_eq!(None, generator.next_with(&Name::Numbered(0, NumberSeperator::Dash)));
+    assert_eq!(Some("rusty-nail".to_string()), generator.next());
+}
+
+#[test]
+fn try_next_with_reports_an_invalid_naming() {
+    let mut generator = rusty_nail().build().unwrap();
+
+    assert_eq!(Err(Error::ZeroDigits), generator.try_next_with(&Name::Numbered(0, NumberSeperator::Dash)));
+    assert_eq!(
+        Err(Error::InvalidNumberRange(9, 1)),
+        generator.try_next_with(&Name::NumberedRange(9, 1, NumberSeperator::Dash))
+    );
+    assert_eq!(Ok("rusty-nail-7".to_string()), generator.try_next_with(&Name::NumberedRange(7, 7, NumberSeperator::Dash)));
+}
+
+#[test]
+fn display_round_trips() {
+    let namings = [
+        Name::Plain,
+        Name::Numbered(4, NumberSeperator::Dash),
+        Name::ZeroPaddedNumbered(2, NumberSeperator::Underscore),
+        Name::Prefixed("svc".into(), NumberSeperator::None),
+        Name::Suffixed("prod".into(), NumberSeperator::Custom("::".into())),
+        Name::HexSuffixed(8, NumberSeperator::Dash),
+        Name::NumberedRange(1, 100, NumberSeperator::Custom(",".into())),
+        Name::Timestamped(NumberSeperator::Underscore),
+        Name::Sequential(NumberSeperator::Dash),
+        Name::VariableNumbered { min_digits: 2, max_digits: 4, sep: NumberSeperator::Dash },
+        Name::EncodedSuffix { chars: 6, alphabet: "0123456789abcdefghjkmnpqrstvwxyz".into(), sep: NumberSeperator::Underscore },
+    ];
+
+    for naming in namings {
+        assert_eq!(Ok(naming.clone()), naming.to_string().parse::<Name>());
+    }
+}
+
+#[test]
+fn from_colon_separated_str() {
+    let cases = [
+        ("plain", Name::Plain),
+        ("numbered:4:-", Name::Numbered(4, NumberSeperator::Dash)),
+        ("zero-padded:3:_", Name::ZeroPaddedNumbered(3, NumberSeperator::Underscore)),
+        ("numbered:2:", Name::Numbered(2, NumberSeperator::None)),
+        ("numbered:2::", Name::Numbered(2, NumberSeperator::Custom(":".into()))),
+        ("prefixed:svc:-", Name::Prefixed("svc".into(), NumberSeperator::Dash)),
+        ("numbered-range:1:100:-", Name::NumberedRange(1, 100, NumberSeperator::Dash)),
+        ("sequential:_", Name::Sequential(NumberSeperator::Underscore)),
+    ];
+
+    for (s, naming) in cases {
+        assert_eq!(Ok(naming), s.parse::<Name>(), "{}", s);
+    }
+    for s in ["numbered:4", "numbered:x:-", "zero-padded", "unknown:4:-"] {
+        assert!(matches!(s.parse::<Name>(), Err(Error::UnknownNaming(_))), "{}", s);
+    }
+}
+
+#[test]
+fn naming_str() {
+    let mut builder = rusty_nail();
+
+    let name = builder.naming_str("zero-padded:3:_").unwrap().build().unwrap().next().unwrap();
+    assert!(Regex::new(r"^rusty-nail_\d{3}$").unwrap().is_match(&name), "{}", name);
+
+    let error = builder.naming_str("numbered:four:-").err().unwrap();
+    assert_eq!("unknown naming: numbered:four:-", error.to_string());
+    let name = builder.build().unwrap().next().unwrap();
+    assert!(name.ends_with(char::is_numeric), "{}", name);
+}
+
+#[test]
+fn display() {
+    assert_eq!("plain", Name::Plain.to_string());
+    assert_eq!("numbered(4,-)", Name::Numbered(4, NumberSeperator::Dash).to_string());
+    assert_eq!("numbered-range(1,100,)", Name::NumberedRange(1, 100, NumberSeperator::None).to_string());
+    for s in ["", "numbered", "numbered(x,-)", "numbered(4)", "unknown(-)"] {
+        assert!(matches!(s.parse::<Name>(), Err(Error::UnknownNaming(_))), "{}", s);
+    }
+}
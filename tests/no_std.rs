@@ -0,0 +1,51 @@
+//! Exercises the library with only `alloc`, run with
+//! `cargo test --no-default-features --test no_std`
+#![cfg(not(feature = "std"))]
+
+use names::{Casing, Error, Generator, GeneratorBuilder, Name, NumberSeperator, SmallRng, StdRng};
+use rand::SeedableRng;
+
+#[test]
+fn generates_without_std() {
+    let mut generator = GeneratorBuilder::default()
+        .casing(Casing::PascalCase)
+        .naming(Name::Numbered(3, NumberSeperator::Underscore))
+        .rng(SmallRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+
+    // PascalCase joins the words without a seperator, so it drops the number's
+    let name = generator.next().unwrap();
+    assert!(name.chars().next().unwrap().is_uppercase());
+    let words = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    assert_eq!(3, name.len() - words.len(), "{}", name);
+    assert!(!words.contains('_'), "{}", name);
+}
+
+#[test]
+fn seeds_without_std() {
+    let mut first = GeneratorBuilder::default().seed(7).build().unwrap();
+    let mut second = GeneratorBuilder::default().seed(7).build().unwrap();
+
+    assert_eq!(first.sample(3), second.sample(3));
+}
+
+#[test]
+fn default_from_an_external_seed() {
+    // the way to generate on `wasm32-unknown-unknown`, which has no entropy
+    // source without `getrandom`'s `js` feature
+    let mut generator = Generator::<StdRng>::from_seed(42);
+    let mut expected = GeneratorBuilder::default().seed(42).build().unwrap();
+
+    assert_eq!(expected.sample(5), generator.sample(5));
+}
+
+#[test]
+fn timestamped_requires_std() {
+    let result = GeneratorBuilder::default()
+        .naming(Name::Timestamped(NumberSeperator::Dash))
+        .seed(0)
+        .build();
+
+    assert_eq!(Some(Error::RequiresStd), result.err());
+}
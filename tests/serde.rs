@@ -0,0 +1,156 @@
+#![cfg(feature = "std")]
+
+use names::{
+    AnyGenerator, Casing, Generator, GeneratorBuilder, GeneratorConfig, Length, Name,
+    NumberSeperator, OsRng, RngKind, SmallRng, StdRng, ThreadRng,
+};
+use rand::SeedableRng;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+fn config() -> Value {
+    let generator = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "shiny".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .naming(Name::ZeroPaddedNumbered(2, NumberSeperator::Underscore))
+        .casing(Casing::CamelCase)
+        .length(Length::Truncate(20))
+        .noun_weights(vec![3])
+        .seed(0)
+        .build()
+        .unwrap();
+
+    serde_json::to_value(generator).unwrap()
+}
+
+fn round_trip<R>()
+where
+    R: rand::Rng,
+    Generator<R>: Serialize + DeserializeOwned,
+{
+    let config = config();
+    let generator = serde_json::from_value::<Generator<R>>(config.clone()).unwrap();
+
+    assert_eq!(config, serde_json::to_value(generator).unwrap());
+}
+
+#[test]
+fn serializes_config() {
+    let config = config();
+
+    assert_eq!(config["adjectives"], serde_json::json!(["rusty", "shiny"]));
+    assert_eq!(config["nouns"], serde_json::json!(["nail"]));
+    assert_eq!(config["naming"], serde_json::json!({ "ZeroPaddedNumbered": [2, "_"] }));
+    assert_eq!(config["casing"], serde_json::json!("CamelCase"));
+    assert_eq!(config["length"], serde_json::json!({ "Truncate": 20 }));
+    assert!(config.get("rng").is_none());
+}
+
+#[test]
+fn round_trip_thread_rng() {
+    round_trip::<ThreadRng>();
+}
+
+#[test]
+fn round_trip_os_rng() {
+    round_trip::<OsRng>();
+}
+
+#[test]
+fn round_trip_std_rng() {
+    round_trip::<StdRng>();
+}
+
+#[test]
+fn round_trip_small_rng() {
+    round_trip::<SmallRng>();
+}
+
+#[test]
+fn any_generator_selects_rng() {
+    let kinds = [
+        ("thread", RngKind::Thread),
+        ("os", RngKind::Os),
+        ("std", RngKind::Std),
+        ("small", RngKind::Small),
+    ];
+
+    for (name, kind) in kinds {
+        let json = format!(r#"{{ "rng": "{}", "nouns": ["nail"], "adjectives": ["rusty"] }}"#, name);
+        let mut generator = serde_json::from_str::<AnyGenerator>(&json).unwrap();
+
+        assert_eq!(kind, generator.rng_kind());
+        assert_eq!(Some("rusty-nail".to_string()), generator.next());
+    }
+}
+
+#[test]
+fn any_generator_defaults_to_thread_rng() {
+    let generator = serde_json::from_str::<AnyGenerator>("{}").unwrap();
+
+    assert_eq!(RngKind::Thread, generator.rng_kind());
+}
+
+#[test]
+fn any_generator_rejects_unknown_rng() {
+    assert!(serde_json::from_str::<AnyGenerator>(r#"{ "rng": "dice" }"#).is_err());
+}
+
+#[test]
+fn config_round_trips() {
+    let json = config();
+    let config = serde_json::from_value::<GeneratorConfig>(json.clone()).unwrap();
+
+    assert_eq!(json, serde_json::to_value(&config).unwrap());
+    assert_eq!(["rusty", "shiny"], config.adjectives.as_slice());
+    assert_eq!(Casing::CamelCase, config.casing);
+}
+
+#[test]
+fn config_with_rng() {
+    let json = config();
+    let config = serde_json::from_value::<GeneratorConfig>(json.clone()).unwrap();
+    let std = config.clone().with_rng(StdRng::seed_from_u64(7)).unwrap();
+    let small = config.clone().with_rng(SmallRng::seed_from_u64(7)).unwrap();
+    let thread = config.clone().with_rng(rand::thread_rng()).unwrap();
+    assert_eq!(json, serde_json::to_value(&small).unwrap());
+    assert_eq!(json, serde_json::to_value(&thread).unwrap());
+    assert_eq!(json, serde_json::to_value(std.config()).unwrap());
+
+    let built = GeneratorBuilder::default()
+        .adjectives(vec!["rusty".to_string(), "shiny".to_string()])
+        .nouns(vec!["nail".to_string()])
+        .naming(Name::ZeroPaddedNumbered(2, NumberSeperator::Underscore))
+        .casing(Casing::CamelCase)
+        .length(Length::Truncate(20))
+        .noun_weights(vec![3])
+        .rng(SmallRng::seed_from_u64(7))
+        .build()
+        .unwrap();
+    assert_eq!(built.take(20).collect::<Vec<_>>(), small.take(20).collect::<Vec<_>>());
+}
+
+#[test]
+fn config_defaults_match_the_builder() {
+    let config = serde_json::from_str::<GeneratorConfig>("{}").unwrap();
+    let generator = GeneratorBuilder::default().seed(0).build().unwrap();
+
+    assert_eq!(serde_json::to_value(&generator).unwrap(), serde_json::to_value(&config).unwrap());
+    assert_eq!(
+        serde_json::to_value(GeneratorConfig::default()).unwrap(),
+        serde_json::to_value(&config).unwrap()
+    );
+}
+
+#[test]
+fn any_generator_reads_the_whole_config() {
+    let json = r#"{ "rng": "small", "nouns": ["nail"], "min_combinations": 100000 }"#;
+    let error = serde_json::from_str::<AnyGenerator>(json).unwrap_err();
+
+    assert!(error.to_string().contains("minimum of 100000"), "{}", error);
+
+    // the "rng" may come anywhere, and a minimum beyond `u64` is still read
+    let json = r#"{ "nouns": ["nail"], "rng": "std", "min_combinations": 340282366920938463463374607431768211455 }"#;
+    let error = serde_json::from_str::<AnyGenerator>(json).unwrap_err();
+    assert!(error.to_string().contains(&u128::MAX.to_string()), "{}", error);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "stream")]
+
+mod common;
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+use common::rusty_nail;
+use futures::StreamExt;
+use names::GeneratorBuilder;
+
+static NOOP: RawWakerVTable = RawWakerVTable::new(|_| noop(), |_| {}, |_| {}, |_| {});
+
+fn noop() -> RawWaker {
+    RawWaker::new(std::ptr::null(), &NOOP)
+}
+
+/// Drives a future which never waits to completion
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = unsafe { Waker::from_raw(noop()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn stream_yields_names() {
+    let mut stream = rusty_nail().build().unwrap().into_stream();
+
+    let names = block_on(async {
+        let mut names = Vec::new();
+        for _ in 0..3 {
+            names.push(stream.next().await.unwrap());
+        }
+        names
+    });
+
+    assert_eq!(vec!["rusty-nail"; 3], names);
+}
+
+#[test]
+fn stream_matches_iterator() {
+    let generator = GeneratorBuilder::default().seed(7).build().unwrap();
+    let expected: Vec<_> = generator.clone().take(3).collect();
+    let mut stream = generator.into_stream();
+
+    let waker = unsafe { Waker::from_raw(noop()) };
+    let mut cx = Context::from_waker(&waker);
+    for name in expected {
+        assert_eq!(Poll::Ready(Some(name)), Pin::new(&mut stream).poll_next(&mut cx));
+    }
+}
+
+#[test]
+fn stream_implements_futures_stream() {
+    let generator = GeneratorBuilder::default().seed(7).build().unwrap();
+    let expected: Vec<_> = generator.clone().take(3).collect();
+    let stream = generator.into_stream();
+
+    let names = futures::executor::block_on(stream.take(3).collect::<Vec<_>>());
+
+    assert_eq!(expected, names);
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "words-file")]
+
+use std::{fs, path::PathBuf, process};
+
+use names::{Error, GeneratorBuilder, StdRng};
+use rand::SeedableRng;
+
+/// Writes the contents to a file in the temporary directory, unique to this
+/// process and test
+fn write_temp(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("names-{}-{}", process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn json() {
+    let path = write_temp("words.json", r#"{ "adjectives": ["rusty"], "nouns": ["nail", "pin"] }"#);
+    let generator = GeneratorBuilder::from_words_file(&path)
+        .unwrap()
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(["rusty"], generator.adjectives());
+    assert_eq!(["nail", "pin"], generator.nouns());
+}
+
+#[test]
+fn toml() {
+    let path = write_temp("words.toml", "adjectives = [\"rusty\"]\nnouns = [\"nail\"]\n");
+    let mut generator = GeneratorBuilder::from_words_file(&path)
+        .unwrap()
+        .rng(StdRng::seed_from_u64(0))
+        .build()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(Some("rusty-nail".to_string()), generator.next());
+}
+
+#[test]
+fn invalid() {
+    let empty = write_temp("empty.json", r#"{ "adjectives": [], "nouns": ["nail"] }"#);
+    let malformed = write_temp("malformed.json", r#"{ "adjectives": ["rusty"] }"#);
+    let missing = std::env::temp_dir().join(format!("names-{}-missing.json", process::id()));
+
+    let empty_result = GeneratorBuilder::<StdRng>::from_words_file(&empty);
+    let malformed_result = GeneratorBuilder::<StdRng>::from_words_file(&malformed);
+    fs::remove_file(&empty).unwrap();
+    fs::remove_file(&malformed).unwrap();
+
+    assert!(matches!(empty_result, Err(Error::AdjectivesEmpty)));
+    assert!(matches!(malformed_result, Err(Error::WordsFile(_))));
+    assert!(matches!(GeneratorBuilder::<StdRng>::from_words_file(missing), Err(Error::WordsFile(_))));
+}